@@ -0,0 +1,122 @@
+//! Compares the relaxed wrappers to raw `SeqCst` atomics and a `Mutex`.
+
+use std::hint::black_box;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use relaxed::{RelaxedF32, RelaxedU32};
+
+const THREADS: usize = 4;
+
+/// Runs `f` `iters` times on each of `THREADS` threads at once and returns the elapsed time.
+fn contended<R>(iters: u64, f: impl Fn() -> R + Sync) -> Duration {
+    let start = Instant::now();
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            scope.spawn(|| {
+                for _ in 0..iters {
+                    black_box(f());
+                }
+            });
+        }
+    });
+    start.elapsed()
+}
+
+fn uncontended(c: &mut Criterion) {
+    let mut group = c.benchmark_group("uncontended");
+
+    let relaxed = RelaxedU32::new(0);
+    let atomic = AtomicU32::new(0);
+    let mutex = Mutex::new(0u32);
+    let relaxed_f32 = RelaxedF32::new(0.0);
+
+    group.bench_function("get/relaxed", |b| b.iter(|| black_box(&relaxed).get()));
+    group.bench_function("get/seq_cst", |b| {
+        b.iter(|| black_box(&atomic).load(Ordering::SeqCst))
+    });
+    group.bench_function("get/mutex", |b| {
+        b.iter(|| *black_box(&mutex).lock().unwrap())
+    });
+
+    group.bench_function("set/relaxed", |b| b.iter(|| black_box(&relaxed).set(1)));
+    group.bench_function("set/seq_cst", |b| {
+        b.iter(|| black_box(&atomic).store(1, Ordering::SeqCst))
+    });
+    group.bench_function("set/mutex", |b| {
+        b.iter(|| *black_box(&mutex).lock().unwrap() = 1)
+    });
+
+    group.bench_function("fetch_add/relaxed", |b| {
+        b.iter(|| black_box(&relaxed).get_and_update(|v| v.wrapping_add(1)))
+    });
+    group.bench_function("fetch_add/relaxed_f32", |b| {
+        b.iter(|| black_box(&relaxed_f32).fetch_add(1.0))
+    });
+    group.bench_function("fetch_add/seq_cst", |b| {
+        b.iter(|| black_box(&atomic).fetch_add(1, Ordering::SeqCst))
+    });
+    group.bench_function("fetch_add/mutex", |b| {
+        b.iter(|| {
+            let mut guard = black_box(&mutex).lock().unwrap();
+            *guard = guard.wrapping_add(1);
+        })
+    });
+
+    group.finish();
+}
+
+fn contended_threads(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contended");
+
+    let relaxed = RelaxedU32::new(0);
+    let atomic = AtomicU32::new(0);
+    let mutex = Mutex::new(0u32);
+    let relaxed_f32 = RelaxedF32::new(0.0);
+
+    group.bench_function("get/relaxed", |b| {
+        b.iter_custom(|iters| contended(iters, || relaxed.get()))
+    });
+    group.bench_function("get/seq_cst", |b| {
+        b.iter_custom(|iters| contended(iters, || atomic.load(Ordering::SeqCst)))
+    });
+    group.bench_function("get/mutex", |b| {
+        b.iter_custom(|iters| contended(iters, || *mutex.lock().unwrap()))
+    });
+
+    group.bench_function("set/relaxed", |b| {
+        b.iter_custom(|iters| contended(iters, || relaxed.set(black_box(1))))
+    });
+    group.bench_function("set/seq_cst", |b| {
+        b.iter_custom(|iters| contended(iters, || atomic.store(black_box(1), Ordering::SeqCst)))
+    });
+    group.bench_function("set/mutex", |b| {
+        b.iter_custom(|iters| contended(iters, || *mutex.lock().unwrap() = black_box(1)))
+    });
+
+    group.bench_function("fetch_add/relaxed", |b| {
+        b.iter_custom(|iters| contended(iters, || relaxed.get_and_update(|v| v.wrapping_add(1))))
+    });
+    group.bench_function("fetch_add/relaxed_f32", |b| {
+        b.iter_custom(|iters| contended(iters, || relaxed_f32.fetch_add(1.0)))
+    });
+    group.bench_function("fetch_add/seq_cst", |b| {
+        b.iter_custom(|iters| contended(iters, || atomic.fetch_add(1, Ordering::SeqCst)))
+    });
+    group.bench_function("fetch_add/mutex", |b| {
+        b.iter_custom(|iters| {
+            contended(iters, || {
+                let mut guard = mutex.lock().unwrap();
+                *guard = guard.wrapping_add(1);
+            })
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, uncontended, contended_threads);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+//! Applies random sequences of operations to a `RelaxedU32` and checks them against a plain `u32`.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use relaxed::{RelaxedU32, UpdateError};
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Set(u32),
+    Swap(u32),
+    CompareExchange(u32, u32),
+    WrappingAdd(u32),
+    CheckedAdd(u32),
+    TryUpdate(u32),
+    Bounded(u32, u8),
+    WithValue(u32),
+}
+
+fuzz_target!(|input: (u32, Vec<Op>)| {
+    let (initial, ops) = input;
+    let value = RelaxedU32::new(initial);
+    let mut model = initial;
+
+    for op in ops {
+        match op {
+            Op::Set(val) => {
+                value.set(val);
+                model = val;
+            }
+            Op::Swap(val) => {
+                assert_eq!(value.swap(val), model);
+                model = val;
+            }
+            Op::CompareExchange(current, new) => {
+                let result = value.compare_exchange(current, new);
+                if model == current {
+                    assert_eq!(result, Ok(model));
+                    model = new;
+                } else {
+                    assert_eq!(result, Err(model));
+                }
+            }
+            Op::WrappingAdd(val) => {
+                assert_eq!(value.get_and_update(|v| v.wrapping_add(val)), model);
+                model = model.wrapping_add(val);
+            }
+            Op::CheckedAdd(val) => match model.checked_add(val) {
+                Some(new) => {
+                    assert_eq!(value.fetch_update(|v| v.checked_add(val)), Ok(model));
+                    model = new;
+                }
+                None => assert_eq!(value.fetch_update(|v| v.checked_add(val)), Err(model)),
+            },
+            Op::TryUpdate(val) => {
+                assert_eq!(value.try_update(|v| v ^ val), Ok(model));
+                model ^= val;
+            }
+            Op::Bounded(val, retries) => {
+                let result = value.fetch_update_bounded(retries.into(), |v| v.checked_sub(val));
+                match model.checked_sub(val) {
+                    Some(new) => {
+                        assert_eq!(result, Ok(model));
+                        model = new;
+                    }
+                    None => assert_eq!(result, Err(UpdateError::Rejected(model))),
+                }
+            }
+            Op::WithValue(val) => {
+                assert_eq!(value.with_value(val, || value.get()), val);
+            }
+        }
+        assert_eq!(value.get(), model);
+    }
+});
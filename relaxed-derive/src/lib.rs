@@ -0,0 +1,168 @@
+//! Derive macros for the [`relaxed`](https://docs.rs/relaxed) crate.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Generates a `Relaxed<Name>` wrapper for a newtype over a primitive with a relaxed wrapper.
+///
+/// The wrapper stores the primitive in its relaxed wrapper, but takes and returns the newtype,
+/// e.g. `RelaxedRpm::get() -> Rpm`. The newtype must be `Copy`.
+///
+/// See the re-export in `relaxed` for an example.
+#[proc_macro_derive(RelaxedOps)]
+pub fn derive_relaxed_ops(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "RelaxedOps can't be derived for generic types",
+        ));
+    }
+    let inner = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "RelaxedOps needs a tuple struct with one field",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "RelaxedOps can only be derived for structs",
+            ))
+        }
+    };
+    let relaxed = format_ident!("Relaxed{}", name);
+    let doc = format!("A [`{name}`] that always uses `Ordering::Relaxed` for access.");
+    let atomic = quote!(<#inner as ::relaxed::Relaxable>::Relaxed);
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[repr(transparent)]
+        #vis struct #relaxed(#atomic);
+
+        #[allow(dead_code)]
+        impl #relaxed {
+            /// Create a new value.
+            #[inline(always)]
+            #vis const fn new(val: #name) -> Self {
+                #relaxed(<#atomic>::new(val.0))
+            }
+
+            /// Performs an atomic load with relaxed ordering.
+            #[inline(always)]
+            #vis fn get(&self) -> #name {
+                #name(self.0.get())
+            }
+            /// Performs an atomic store with relaxed ordering.
+            #[inline(always)]
+            #vis fn set(&self, val: #name) {
+                self.0.set(val.0)
+            }
+            /// Stores a value, returning the previous value.
+            #[inline(always)]
+            #vis fn swap(&self, val: #name) -> #name {
+                #name(self.0.swap(val.0))
+            }
+            /// Stores `new` if the current value is `current`.
+            /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+            #[inline(always)]
+            #vis fn compare_exchange(
+                &self,
+                current: #name,
+                new: #name,
+            ) -> ::core::result::Result<#name, #name> {
+                self.0.compare_exchange(current.0, new.0).map(#name).map_err(#name)
+            }
+            /// Fetches the value, and applies `f` to it that returns an optional new value.
+            /// Returns `Ok(previous_value)` if `f` returned `Some`, else `Err(previous_value)`.
+            #[inline(always)]
+            #vis fn fetch_update(
+                &self,
+                mut f: impl ::core::ops::FnMut(#name) -> ::core::option::Option<#name>,
+            ) -> ::core::result::Result<#name, #name> {
+                self.0
+                    .fetch_update(|val| f(#name(val)).map(|new| new.0))
+                    .map(#name)
+                    .map_err(#name)
+            }
+            /// Atomically replaces the value with the result of `f` and returns the previous value.
+            #[inline(always)]
+            #vis fn get_and_update(
+                &self,
+                mut f: impl ::core::ops::FnMut(#name) -> #name,
+            ) -> #name {
+                #name(self.0.get_and_update(|val| f(#name(val)).0))
+            }
+            /// Atomically replaces the value with the result of `f` and returns the new value.
+            #[inline(always)]
+            #vis fn update_and_get(
+                &self,
+                mut f: impl ::core::ops::FnMut(#name) -> #name,
+            ) -> #name {
+                #name(self.0.update_and_get(|val| f(#name(val)).0))
+            }
+        }
+
+        impl ::relaxed::RelaxedValue for #relaxed {
+            type Value = #name;
+
+            #[inline(always)]
+            fn get(&self) -> #name {
+                #relaxed::get(self)
+            }
+            #[inline(always)]
+            fn set(&self, val: #name) {
+                #relaxed::set(self, val)
+            }
+            #[inline(always)]
+            fn swap(&self, val: #name) -> #name {
+                #relaxed::swap(self, val)
+            }
+            #[inline(always)]
+            fn compare_exchange(
+                &self,
+                current: #name,
+                new: #name,
+            ) -> ::core::result::Result<#name, #name> {
+                #relaxed::compare_exchange(self, current, new)
+            }
+        }
+
+        impl ::relaxed::Relaxable for #name {
+            type Relaxed = #relaxed;
+        }
+
+        impl ::core::default::Default for #relaxed {
+            fn default() -> Self {
+                #relaxed(::core::default::Default::default())
+            }
+        }
+
+        impl ::core::fmt::Debug for #relaxed {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(::core::stringify!(#relaxed))
+                    .field(&self.0.get())
+                    .finish()
+            }
+        }
+
+        impl ::core::convert::From<#name> for #relaxed {
+            fn from(val: #name) -> Self {
+                #relaxed::new(val)
+            }
+        }
+    })
+}
@@ -0,0 +1,109 @@
+use alloc::sync::Arc;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+
+use crate::Backoff;
+
+/// An [`Arc<T>`] that can be replaced atomically while other threads load it,
+/// e.g. to publish new immutable configuration snapshots.
+///
+/// Loads and stores take a short internal spin lock around the reference count update,
+/// which also makes the contents of a stored `Arc` visible to the threads loading it.
+///
+/// ```
+/// use std::sync::Arc;
+/// use relaxed::RelaxedArc;
+///
+/// let config = RelaxedArc::new(Arc::new("v1"));
+/// let old = config.load();
+///
+/// config.store(Arc::new("v2"));
+/// assert_eq!(*old, "v1");
+/// assert_eq!(*config.load(), "v2");
+/// ```
+pub struct RelaxedArc<T> {
+    ptr: AtomicPtr<T>,
+    locked: AtomicBool,
+    _arc: PhantomData<Arc<T>>,
+}
+
+impl<T> RelaxedArc<T> {
+    /// Create a new value.
+    pub fn new(val: Arc<T>) -> Self {
+        RelaxedArc {
+            ptr: AtomicPtr::new(Arc::into_raw(val).cast_mut()),
+            locked: AtomicBool::new(false),
+            _arc: PhantomData,
+        }
+    }
+
+    fn with_lock<R>(&self, f: impl FnOnce() -> R) -> R {
+        let mut backoff = Backoff::new();
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            backoff.snooze();
+        }
+        let result = f();
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+
+    /// Returns a new reference to the current `Arc`.
+    pub fn load(&self) -> Arc<T> {
+        self.with_lock(|| {
+            let ptr = self.ptr.load(Ordering::Relaxed);
+            // SAFETY: `ptr` came from `Arc::into_raw` and the lock
+            // keeps it from being released by a concurrent swap
+            unsafe {
+                Arc::increment_strong_count(ptr);
+                Arc::from_raw(ptr)
+            }
+        })
+    }
+    /// Replaces the current `Arc`.
+    pub fn store(&self, val: Arc<T>) {
+        drop(self.swap(val))
+    }
+    /// Replaces the current `Arc`, returning the previous one.
+    pub fn swap(&self, val: Arc<T>) -> Arc<T> {
+        let new = Arc::into_raw(val).cast_mut();
+        let previous = self.with_lock(|| self.ptr.swap(new, Ordering::Relaxed));
+        // SAFETY: `previous` came from `Arc::into_raw` and its reference is no longer stored
+        unsafe { Arc::from_raw(previous) }
+    }
+}
+
+impl<T> Drop for RelaxedArc<T> {
+    fn drop(&mut self) {
+        // SAFETY: the pointer came from `Arc::into_raw` and is not used after this
+        unsafe { drop(Arc::from_raw(*self.ptr.get_mut())) }
+    }
+}
+
+impl<T: Default> Default for RelaxedArc<T> {
+    fn default() -> Self {
+        RelaxedArc::new(Arc::default())
+    }
+}
+
+impl<T: Debug> Debug for RelaxedArc<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.load(), f)
+    }
+}
+
+impl<T> From<Arc<T>> for RelaxedArc<T> {
+    fn from(val: Arc<T>) -> Self {
+        RelaxedArc::new(val)
+    }
+}
+
+impl<T> From<T> for RelaxedArc<T> {
+    fn from(val: T) -> Self {
+        RelaxedArc::new(Arc::new(val))
+    }
+}
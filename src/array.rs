@@ -0,0 +1,118 @@
+use core::fmt::{Debug, Formatter};
+use core::ops::Deref;
+
+use crate::{Relaxable, RelaxedSliceExt, RelaxedValue};
+
+/// A fixed size array of relaxed values, e.g. a mirror of ADC channels.
+///
+/// Dereferences to a slice of the wrappers, so single elements are accessed by indexing.
+/// The bulk operations access every element with its own relaxed load or store,
+/// so the array as a whole is not read or written atomically.
+///
+/// ```
+/// use relaxed::{RelaxedArray, RelaxedU16};
+///
+/// static ADC: RelaxedArray<u16, 4> = RelaxedArray::from_cells([const { RelaxedU16::new(0) }; 4]);
+///
+/// // in the DMA complete interrupt:
+/// ADC.copy_from_slice(&[512, 1023, 0, 77]);
+///
+/// // in the main loop:
+/// let mut channels = [0; 4];
+/// ADC.load_into(&mut channels);
+/// assert_eq!(channels, [512, 1023, 0, 77]);
+/// assert_eq!(ADC[1].get(), 1023);
+///
+/// ADC.fill(0);
+/// assert_eq!(ADC.load(), [0; 4]);
+/// ```
+#[repr(transparent)]
+pub struct RelaxedArray<T: Relaxable, const N: usize>([T::Relaxed; N]);
+
+impl<T: Relaxable, const N: usize> RelaxedArray<T, N> {
+    /// Create a new array from its elements.
+    #[inline(always)]
+    pub fn new(val: [T; N]) -> Self
+    where
+        T::Relaxed: From<T>,
+    {
+        RelaxedArray(val.map(From::from))
+    }
+
+    /// Create a new array from its relaxed elements.
+    ///
+    /// Unlike [`new`](Self::new) this is `const`, so it can initialize a `static`.
+    #[inline(always)]
+    pub const fn from_cells(cells: [T::Relaxed; N]) -> Self {
+        RelaxedArray(cells)
+    }
+
+    /// Stores `val` in every element.
+    pub fn fill(&self, val: T) {
+        for cell in &self.0 {
+            cell.set(val);
+        }
+    }
+    /// Stores every element of `src`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` doesn't have `N` elements.
+    pub fn copy_from_slice(&self, src: &[T]) {
+        self.0.store_all(src)
+    }
+    /// Loads every element into `dst`.
+    pub fn load_into(&self, dst: &mut [T; N]) {
+        self.0.load_all(dst)
+    }
+    /// Loads every element.
+    pub fn load(&self) -> [T; N] {
+        core::array::from_fn(|i| self.0[i].get())
+    }
+}
+
+impl<T: Relaxable, const N: usize> Deref for RelaxedArray<T, N> {
+    type Target = [T::Relaxed];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T::Relaxed] {
+        &self.0
+    }
+}
+
+impl<T: Relaxable, const N: usize> Default for RelaxedArray<T, N>
+where
+    T::Relaxed: Default,
+{
+    fn default() -> Self {
+        RelaxedArray(core::array::from_fn(|_| Default::default()))
+    }
+}
+
+impl<T: Relaxable + Debug, const N: usize> Debug for RelaxedArray<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(RelaxedValue::get))
+            .finish()
+    }
+}
+
+impl<T: Relaxable, const N: usize> From<[T; N]> for RelaxedArray<T, N>
+where
+    T::Relaxed: From<T>,
+{
+    fn from(val: [T; N]) -> Self {
+        Self::new(val)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Relaxable + serde::Serialize, const N: usize> serde::Serialize for RelaxedArray<T, N> {
+    /// Serializes every element as a sequence, with one relaxed load per element.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.0.iter().map(RelaxedValue::get))
+    }
+}
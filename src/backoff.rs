@@ -0,0 +1,110 @@
+/// A strategy for waiting between the attempts of a retrying update,
+/// e.g. [`RelaxedU32::fetch_update_with`](crate::RelaxedU32::fetch_update_with).
+///
+/// Implemented for closures taking the number of the upcoming retry, starting at 1.
+pub trait BackoffStrategy {
+    /// Called before every retry with the number of the retry, starting at 1.
+    fn backoff(&mut self, retry: u32);
+}
+
+/// Retries immediately.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoBackoff;
+
+impl BackoffStrategy for NoBackoff {
+    #[inline(always)]
+    fn backoff(&mut self, _retry: u32) {}
+}
+
+impl<F: FnMut(u32)> BackoffStrategy for F {
+    #[inline(always)]
+    fn backoff(&mut self, retry: u32) {
+        self(retry)
+    }
+}
+
+/// Exponential backoff for spin loops.
+///
+/// [`spin`](Self::spin) issues a growing number of [`spin_loop`](core::hint::spin_loop) hints,
+/// [`snooze`](Self::snooze) does the same but yields to the OS scheduler once spinning gets long,
+/// if the `std` feature is enabled.
+///
+/// ```
+/// use relaxed::{Backoff, RelaxedU32};
+///
+/// let value = RelaxedU32::new(0);
+/// let mut backoff = Backoff::new();
+/// let mut current = value.get();
+/// while let Err(actual) = value.compare_exchange(current, current + 1) {
+///     backoff.spin();
+///     current = actual;
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Backoff {
+    step: u32,
+}
+
+impl Backoff {
+    const SPIN_LIMIT: u32 = 6;
+    const YIELD_LIMIT: u32 = 10;
+
+    /// Create a new backoff.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Backoff { step: 0 }
+    }
+
+    /// Starts over with the shortest wait.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.step = 0;
+    }
+    /// Spins for a while, waiting twice as long as the previous call, up to a limit.
+    ///
+    /// Use this when retrying a failed compare and exchange.
+    #[inline]
+    pub fn spin(&mut self) {
+        for _ in 0..1 << self.step.min(Self::SPIN_LIMIT) {
+            core::hint::spin_loop();
+        }
+        if self.step <= Self::SPIN_LIMIT {
+            self.step += 1;
+        }
+    }
+    /// Like [`spin`](Self::spin), but yields the thread once spinning got long.
+    ///
+    /// Use this when waiting for another thread to make progress.
+    /// Without the `std` feature, this always spins.
+    #[inline]
+    pub fn snooze(&mut self) {
+        if self.step <= Self::SPIN_LIMIT {
+            for _ in 0..1 << self.step {
+                core::hint::spin_loop();
+            }
+        } else {
+            #[cfg(feature = "std")]
+            std::thread::yield_now();
+            #[cfg(not(feature = "std"))]
+            for _ in 0..1 << Self::SPIN_LIMIT {
+                core::hint::spin_loop();
+            }
+        }
+        if self.step <= Self::YIELD_LIMIT {
+            self.step += 1;
+        }
+    }
+    /// Returns `true` once snoozing has reached yielding,
+    /// a hint to switch to a blocking wait if one is available.
+    #[inline(always)]
+    pub fn is_completed(&self) -> bool {
+        self.step > Self::YIELD_LIMIT
+    }
+}
+
+impl BackoffStrategy for Backoff {
+    #[inline(always)]
+    fn backoff(&mut self, _retry: u32) {
+        self.spin()
+    }
+}
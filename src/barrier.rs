@@ -0,0 +1,73 @@
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::Backoff;
+
+/// A spinning barrier for `N` participants, e.g. the cores of a dual-core MCU during startup.
+///
+/// Everything a participant wrote before arriving is visible to all participants
+/// after they leave the barrier, so it can publish data initialized by another core.
+/// The barrier can be reused once all participants have left it.
+///
+/// ```
+/// use relaxed::StartBarrier;
+///
+/// static STARTUP: StartBarrier<2> = StartBarrier::new();
+///
+/// let other_core = std::thread::spawn(|| STARTUP.arrive_and_wait());
+/// let leaders = [STARTUP.arrive_and_wait(), other_core.join().unwrap()];
+/// assert_eq!(leaders.iter().filter(|&&leader| leader).count(), 1);
+/// ```
+pub struct StartBarrier<const N: usize> {
+    arrived: AtomicU32,
+    generation: AtomicU32,
+}
+
+impl<const N: usize> StartBarrier<N> {
+    /// Create a new barrier.
+    pub const fn new() -> Self {
+        StartBarrier {
+            arrived: AtomicU32::new(0),
+            generation: AtomicU32::new(0),
+        }
+    }
+
+    /// Spins until all `N` participants have arrived.
+    ///
+    /// Returns `true` for exactly one participant, the last one to arrive.
+    pub fn arrive_and_wait(&self) -> bool {
+        const { assert!(N > 0 && N <= u32::MAX as usize) };
+
+        let generation = self.generation.load(Ordering::Acquire);
+        if self.arrived.fetch_add(1, Ordering::AcqRel) + 1 == N as u32 {
+            self.arrived.store(0, Ordering::Relaxed);
+            self.generation
+                .store(generation.wrapping_add(1), Ordering::Release);
+            return true;
+        }
+        let mut backoff = Backoff::new();
+        while self.generation.load(Ordering::Acquire) == generation {
+            backoff.snooze();
+        }
+        false
+    }
+    /// Returns the number of participants waiting at the barrier.
+    pub fn arrived(&self) -> usize {
+        self.arrived.load(Ordering::Relaxed) as usize
+    }
+}
+
+impl<const N: usize> Default for StartBarrier<N> {
+    fn default() -> Self {
+        StartBarrier::new()
+    }
+}
+
+impl<const N: usize> Debug for StartBarrier<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StartBarrier")
+            .field("participants", &N)
+            .field("arrived", &self.arrived())
+            .finish()
+    }
+}
@@ -0,0 +1,132 @@
+use core::cell::RefCell;
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::vec::Vec;
+
+/// The counts of this thread that weren't added to their counter's total yet.
+struct Pending(Vec<(&'static BatchedCounter, u64)>);
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        for &(counter, count) in &self.0 {
+            counter.total.fetch_add(count, Ordering::Relaxed);
+        }
+    }
+}
+
+std::thread_local! {
+    static PENDING: RefCell<Pending> = const { RefCell::new(Pending(Vec::new())) };
+}
+
+/// A hot event counter that batches increments per thread.
+///
+/// Each thread adds to its own pending count and only adds it to the shared total
+/// once it reaches `batch`, when the thread reads the counter, or when the thread exits.
+/// This avoids contending for the total's cache line on every increment.
+///
+/// [`get`](Self::get) only includes the pending counts of the calling thread,
+/// so it may lag behind by up to `batch - 1` events for every other thread.
+///
+/// ```
+/// use relaxed::BatchedCounter;
+///
+/// static REQUESTS: BatchedCounter = BatchedCounter::new(64);
+///
+/// let workers: Vec<_> = (0..4)
+///     .map(|_| {
+///         std::thread::spawn(|| {
+///             for _ in 0..1000 {
+///                 REQUESTS.increment();
+///             }
+///         })
+///     })
+///     .collect();
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+/// // exited threads have flushed their pending counts
+/// assert_eq!(REQUESTS.get(), 4000);
+/// ```
+pub struct BatchedCounter {
+    total: AtomicU64,
+    batch: u64,
+}
+
+impl BatchedCounter {
+    /// Create a new counter at zero that flushes every `batch` events.
+    ///
+    /// # Panics
+    /// Panics if `batch` is zero.
+    #[inline(always)]
+    pub const fn new(batch: u64) -> Self {
+        assert!(batch > 0, "batch must be non-zero");
+        BatchedCounter {
+            total: AtomicU64::new(0),
+            batch,
+        }
+    }
+
+    /// Adds one event.
+    #[inline(always)]
+    pub fn increment(&'static self) {
+        self.add(1)
+    }
+    /// Adds `n` events.
+    pub fn add(&'static self, n: u64) {
+        let batched = PENDING.try_with(|pending| {
+            let pending = &mut pending.borrow_mut().0;
+            let index = match pending
+                .iter()
+                .position(|&(counter, _)| core::ptr::eq(counter, self))
+            {
+                Some(index) => index,
+                None => {
+                    pending.push((self, 0));
+                    pending.len() - 1
+                }
+            };
+            let count = &mut pending[index].1;
+            *count += n;
+            if *count >= self.batch {
+                self.total.fetch_add(*count, Ordering::Relaxed);
+                *count = 0;
+            }
+        });
+        // the thread local is already destroyed while the thread exits
+        if batched.is_err() {
+            self.total.fetch_add(n, Ordering::Relaxed);
+        }
+    }
+    /// Adds the pending events of the calling thread to the total.
+    pub fn flush(&'static self) {
+        let _ = PENDING.try_with(|pending| {
+            let pending = &mut pending.borrow_mut().0;
+            if let Some(index) = pending
+                .iter()
+                .position(|&(counter, _)| core::ptr::eq(counter, self))
+            {
+                let (_, count) = pending.swap_remove(index);
+                self.total.fetch_add(count, Ordering::Relaxed);
+            }
+        });
+    }
+    /// Flushes the calling thread and returns the total.
+    pub fn get(&'static self) -> u64 {
+        self.flush();
+        self.flushed()
+    }
+    /// Returns the total without flushing the calling thread.
+    #[inline(always)]
+    pub fn flushed(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+}
+
+impl Debug for BatchedCounter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BatchedCounter")
+            .field("flushed", &self.flushed())
+            .field("batch", &self.batch)
+            .finish()
+    }
+}
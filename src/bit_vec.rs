@@ -0,0 +1,180 @@
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+const BITS: usize = usize::BITS as usize;
+
+/// A heap-allocated bitset of relaxed atomic words.
+///
+/// Bits can be set, cleared and tested concurrently through a shared reference,
+/// growing the bitset needs exclusive access.
+///
+/// ```
+/// use relaxed::RelaxedBitVec;
+///
+/// let mut ready = RelaxedBitVec::new(100);
+/// ready.set(3);
+/// ready.set(64);
+/// assert!(ready.test(64));
+/// assert_eq!(ready.count_ones(), 2);
+///
+/// ready.resize(1000);
+/// ready.set(999);
+/// assert_eq!(ready.iter_ones().collect::<Vec<_>>(), [3, 64, 999]);
+/// ```
+pub struct RelaxedBitVec {
+    words: Vec<AtomicUsize>,
+    len: usize,
+}
+
+impl RelaxedBitVec {
+    /// Create a new bitset of `len` cleared bits.
+    pub fn new(len: usize) -> Self {
+        let mut bits = RelaxedBitVec {
+            words: Vec::new(),
+            len: 0,
+        };
+        bits.resize(len);
+        bits
+    }
+
+    /// Returns the number of bits.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns `true` if there are no bits.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Grows or shrinks the bitset to `len` bits, new bits are cleared.
+    pub fn resize(&mut self, len: usize) {
+        if len < self.len {
+            // clear the bits past the new end, so growing again starts with cleared bits
+            for index in len..self.len.min(len.next_multiple_of(BITS)) {
+                self.clear(index);
+            }
+        }
+        self.words
+            .resize_with(len.div_ceil(BITS), || AtomicUsize::new(0));
+        self.len = len;
+    }
+
+    fn locate(&self, index: usize) -> (&AtomicUsize, usize) {
+        assert!(
+            index < self.len,
+            "index {index} out of bounds for length {}",
+            self.len
+        );
+        (&self.words[index / BITS], 1 << (index % BITS))
+    }
+
+    /// Sets the bit at `index`, returning its previous state.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn set(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        word.fetch_or(mask, Ordering::Relaxed) & mask != 0
+    }
+    /// Clears the bit at `index`, returning its previous state.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn clear(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        word.fetch_and(!mask, Ordering::Relaxed) & mask != 0
+    }
+    /// Returns the state of the bit at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn test(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        word.load(Ordering::Relaxed) & mask != 0
+    }
+    /// Clears every bit.
+    pub fn clear_all(&self) {
+        for word in &self.words {
+            word.store(0, Ordering::Relaxed);
+        }
+    }
+    /// Returns the number of set bits.
+    ///
+    /// The words are loaded one after another, so the count is not atomic as a whole.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.load(Ordering::Relaxed).count_ones() as usize)
+            .sum()
+    }
+    /// Returns an iterator over the indices of the set bits, in ascending order.
+    pub fn iter_ones(&self) -> IterOnes<'_> {
+        IterOnes {
+            words: &self.words,
+            word_index: 0,
+            current: self.words.first().map_or(0, |w| w.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl Debug for RelaxedBitVec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter_ones()).finish()
+    }
+}
+
+/// An iterator over the indices of the set bits of a [`RelaxedBitVec`].
+///
+/// Each word is loaded when the iterator reaches it.
+#[derive(Debug)]
+pub struct IterOnes<'a> {
+    words: &'a [AtomicUsize],
+    word_index: usize,
+    current: usize,
+}
+
+impl Iterator for IterOnes<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.word_index += 1;
+            self.current = self.words.get(self.word_index)?.load(Ordering::Relaxed);
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        Some(self.word_index * BITS + bit)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RelaxedBitVec {
+    /// Serializes the bitset as a struct of its length and its words, lowest bits first.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        struct Words<'a>(&'a [AtomicUsize]);
+
+        impl serde::Serialize for Words<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.collect_seq(self.0.iter().map(|word| word.load(Ordering::Relaxed)))
+            }
+        }
+
+        let mut state = serializer.serialize_struct("RelaxedBitVec", 2)?;
+        state.serialize_field("len", &self.len)?;
+        state.serialize_field("words", &Words(&self.words))?;
+        state.end()
+    }
+}
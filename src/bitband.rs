@@ -0,0 +1,214 @@
+//! Flags in the bit-band regions of Cortex-M3 and Cortex-M4 cores.
+//!
+//! Every bit in the first megabyte of SRAM and of the peripheral region is mirrored by a word
+//! in an alias region. Writing to the alias word sets or clears just that bit with a single store,
+//! where an atomic read-modify-write of the containing word needs an exclusive load/store loop.
+
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+const SRAM: usize = 0x2000_0000;
+const SRAM_ALIAS: usize = 0x2200_0000;
+const PERIPHERAL: usize = 0x4000_0000;
+const PERIPHERAL_ALIAS: usize = 0x4200_0000;
+const REGION_SIZE: usize = 0x0010_0000;
+
+/// Returns the bit-band alias address of `bit` in the word at `addr`, if it is in a bit-band region.
+fn alias(addr: usize, bit: u32) -> Option<*mut u32> {
+    let (base, alias_base) = if (SRAM..SRAM + REGION_SIZE).contains(&addr) {
+        (SRAM, SRAM_ALIAS)
+    } else if (PERIPHERAL..PERIPHERAL + REGION_SIZE).contains(&addr) {
+        (PERIPHERAL, PERIPHERAL_ALIAS)
+    } else {
+        return None;
+    };
+    Some((alias_base + (addr - base) * 32 + bit as usize * 4) as *mut u32)
+}
+
+/// 32 flags packed into one word, that can be set and cleared individually with a single store.
+///
+/// The store goes through the bit-band alias region, so the word must be placed in the bit-band
+/// region of SRAM. Cortex-M7 and other cores don't implement bit-banding, so this type must only
+/// be used on Cortex-M3 and Cortex-M4. If the word is not in a bit-band region,
+/// an atomic read-modify-write is used instead.
+#[derive(Default)]
+#[repr(transparent)]
+pub struct BitbandFlags(AtomicU32);
+
+impl BitbandFlags {
+    /// Create a new value.
+    #[inline(always)]
+    pub const fn new(bits: u32) -> Self {
+        BitbandFlags(AtomicU32::new(bits))
+    }
+
+    /// Performs an atomic load of all flags with relaxed ordering.
+    #[inline(always)]
+    pub fn bits(&self) -> u32 {
+        self.0.load(Ordering::Relaxed)
+    }
+    /// Performs an atomic store of all flags with relaxed ordering.
+    #[inline(always)]
+    pub fn set_bits(&self, bits: u32) {
+        self.0.store(bits, Ordering::Relaxed)
+    }
+
+    /// Returns the flag at `bit`.
+    ///
+    /// # Panics
+    /// Panics if `bit` is not less than 32.
+    #[inline(always)]
+    pub fn get(&self, bit: u32) -> bool {
+        assert!(bit < 32);
+        self.bits() & (1 << bit) != 0
+    }
+    /// Sets the flag at `bit` without touching the others.
+    ///
+    /// # Panics
+    /// Panics if `bit` is not less than 32.
+    #[inline(always)]
+    pub fn set(&self, bit: u32, val: bool) {
+        assert!(bit < 32);
+        match alias(self.0.as_ptr() as usize, bit) {
+            // SAFETY: the alias word of a bit in a bit-band region is always valid for writes,
+            // and writing to it is equivalent to an atomic update of that single bit
+            Some(alias) => unsafe { alias.write_volatile(val as u32) },
+            None if val => {
+                self.0.fetch_or(1 << bit, Ordering::Relaxed);
+            }
+            None => {
+                self.0.fetch_and(!(1 << bit), Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Returns a handle to the flag at `bit`.
+    ///
+    /// # Panics
+    /// Panics if `bit` is not less than 32.
+    #[inline(always)]
+    pub fn flag(&self, bit: u32) -> BitbandBool<'_> {
+        assert!(bit < 32);
+        BitbandBool { flags: self, bit }
+    }
+}
+
+impl Debug for BitbandFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#034b}", self.bits())
+    }
+}
+
+impl From<u32> for BitbandFlags {
+    fn from(bits: u32) -> Self {
+        BitbandFlags::new(bits)
+    }
+}
+
+/// A single flag of [`BitbandFlags`], with the same access methods as [`RelaxedBool`](crate::RelaxedBool).
+///
+/// A `RelaxedBool` that has a word to itself is already set with a single store.
+/// Bit-banding pays off when several flags share a word, so this is a handle into
+/// [`BitbandFlags`] rather than a standalone value.
+///
+/// Only plain stores go through the bit-band alias. The read-modify-write methods
+/// update the bit with an atomic read-modify-write of the whole word.
+#[derive(Clone, Copy)]
+pub struct BitbandBool<'a> {
+    flags: &'a BitbandFlags,
+    bit: u32,
+}
+
+impl BitbandBool<'_> {
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> bool {
+        self.flags.get(self.bit)
+    }
+    /// Sets or clears the flag with a single store to the bit-band alias.
+    #[inline(always)]
+    pub fn set(&self, val: bool) {
+        self.flags.set(self.bit, val)
+    }
+    /// Stores a value, returning the previous value.
+    #[inline(always)]
+    pub fn swap(&self, val: bool) -> bool {
+        let previous = if val {
+            self.flags.0.fetch_or(self.mask(), Ordering::Relaxed)
+        } else {
+            self.flags.0.fetch_and(!self.mask(), Ordering::Relaxed)
+        };
+        previous & self.mask() != 0
+    }
+    /// Stores `new` if the current value is `current`.
+    /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+    #[inline(always)]
+    pub fn compare_exchange(&self, current: bool, new: bool) -> Result<bool, bool> {
+        self.fetch_update(|val| (val == current).then_some(new))
+    }
+    /// Calls `f` with the current value and stores the value returned by `f`.
+    /// **The update is not atomic.**
+    #[inline(always)]
+    pub fn update(&self, f: impl FnOnce(bool) -> bool) {
+        self.set(f(self.get()))
+    }
+    /// Fetches the value, and applies `f` to it that returns an optional new value.
+    /// Returns `Ok(previous_value)` if `f` returned `Some`, else `Err(previous_value)`.
+    ///
+    /// `f` may be called multiple times if the word was changed by other threads
+    /// in the meantime, but the new value is stored atomically.
+    #[inline(always)]
+    pub fn fetch_update(&self, mut f: impl FnMut(bool) -> Option<bool>) -> Result<bool, bool> {
+        let mask = self.mask();
+        self.flags
+            .0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |word| {
+                f(word & mask != 0).map(|val| if val { word | mask } else { word & !mask })
+            })
+            .map(|previous| previous & mask != 0)
+            .map_err(|current| current & mask != 0)
+    }
+    /// Performs a logical "not" operation on the current value, and sets the new value to the result.
+    /// Returns the previous value.
+    #[inline(always)]
+    pub fn fetch_not(&self) -> bool {
+        self.flags.0.fetch_xor(self.mask(), Ordering::Relaxed) & self.mask() != 0
+    }
+
+    /// Sets the flag to `true` and returns a guard that resets it to `false` when dropped.
+    pub fn guard(&self) -> BitbandGuard<'_> {
+        self.set(true);
+        BitbandGuard(*self)
+    }
+    /// Sets the flag to `true` and returns a guard that resets it to `false` when dropped.
+    /// Returns `None` if the flag was already set.
+    pub fn try_guard(&self) -> Option<BitbandGuard<'_>> {
+        if self.swap(true) {
+            None
+        } else {
+            Some(BitbandGuard(*self))
+        }
+    }
+
+    #[inline(always)]
+    fn mask(&self) -> u32 {
+        1 << self.bit
+    }
+}
+
+impl Debug for BitbandBool<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+/// Resets a [`BitbandBool`] to `false` when dropped, see [`BitbandBool::guard`].
+#[must_use = "the flag is reset immediately if the guard is not held"]
+#[derive(Debug)]
+pub struct BitbandGuard<'a>(BitbandBool<'a>);
+
+impl Drop for BitbandGuard<'_> {
+    fn drop(&mut self) {
+        self.0.set(false)
+    }
+}
@@ -0,0 +1,100 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::RelaxedU32;
+
+/// A Bloom filter of `WORDS` 32-bit words, setting `K` bits per inserted hash.
+///
+/// [`maybe_contains`](Self::maybe_contains) never misses an inserted hash,
+/// but may report hashes that were never inserted, more often the fuller the filter gets.
+/// The `K` bit indices are derived from the low and high half of the 64-bit hash.
+///
+/// ```
+/// use relaxed::RelaxedBloom;
+///
+/// static LOGGED: RelaxedBloom<8, 3> = RelaxedBloom::new();
+///
+/// fn log_once(error_id: u64) {
+///     if !LOGGED.insert(error_id) {
+///         println!("error {error_id}");
+///     }
+/// }
+///
+/// log_once(0x1234_5678_9abc_def0);
+/// assert!(LOGGED.maybe_contains(0x1234_5678_9abc_def0));
+/// assert!(LOGGED.insert(0x1234_5678_9abc_def0));
+/// ```
+pub struct RelaxedBloom<const WORDS: usize, const K: usize> {
+    words: [RelaxedU32; WORDS],
+}
+
+impl<const WORDS: usize, const K: usize> RelaxedBloom<WORDS, K> {
+    /// Create a new empty filter.
+    pub const fn new() -> Self {
+        const { assert!(WORDS > 0 && K > 0, "WORDS and K must be non-zero") };
+        RelaxedBloom {
+            words: [const { RelaxedU32::new(0) }; WORDS],
+        }
+    }
+
+    /// Returns the `K` bit indices of `hash`, using double hashing.
+    fn bits(hash: u64) -> impl Iterator<Item = (usize, u32)> {
+        let (h1, h2) = (hash as u32 as u64, (hash >> 32) | 1);
+        let len = WORDS as u64 * 32;
+        (0..K as u64).map(move |i| {
+            let bit = h1.wrapping_add(i.wrapping_mul(h2)) % len;
+            ((bit / 32) as usize, 1 << (bit % 32))
+        })
+    }
+
+    /// Inserts `hash`, returning `true` if it was possibly inserted before.
+    pub fn insert(&self, hash: u64) -> bool {
+        let mut present = true;
+        for (word, mask) in Self::bits(hash) {
+            present &= self.words[word].fetch_or_bits(mask) & mask != 0;
+        }
+        present
+    }
+    /// Returns `true` if `hash` was possibly inserted, `false` if it definitely wasn't.
+    pub fn maybe_contains(&self, hash: u64) -> bool {
+        Self::bits(hash).all(|(word, mask)| self.words[word].get() & mask != 0)
+    }
+    /// Removes every hash.
+    pub fn clear(&self) {
+        for word in &self.words {
+            word.set(0);
+        }
+    }
+    /// Returns the number of set bits, to estimate how full the filter is.
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|word| word.get().count_ones()).sum()
+    }
+    /// Returns the filter as individual relaxed words.
+    pub fn words(&self) -> &[RelaxedU32; WORDS] {
+        &self.words
+    }
+}
+
+impl<const WORDS: usize, const K: usize> Default for RelaxedBloom<WORDS, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WORDS: usize, const K: usize> Debug for RelaxedBloom<WORDS, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RelaxedBloom")
+            .field("count_ones", &self.count_ones())
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const WORDS: usize, const K: usize> serde::Serialize for RelaxedBloom<WORDS, K> {
+    /// Serializes the filter as a sequence of its words.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.words.iter().map(RelaxedU32::get))
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{RelaxedU32, RelaxedU8};
+
+/// Copies `src` into the relaxed bytes `dst`, one relaxed store per byte.
+///
+/// Useful to keep a shadow copy of a DMA buffer that other contexts read concurrently,
+/// without casting the buffer to atomics.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// ```
+/// use relaxed::{copy_from_relaxed, copy_to_relaxed, RelaxedU8};
+///
+/// static SHADOW: [RelaxedU8; 4] = [const { RelaxedU8::new(0) }; 4];
+///
+/// copy_to_relaxed(&SHADOW, b"ping");
+///
+/// let mut frame = [0; 4];
+/// copy_from_relaxed(&mut frame, &SHADOW);
+/// assert_eq!(&frame, b"ping");
+/// ```
+pub fn copy_to_relaxed(dst: &[RelaxedU8], src: &[u8]) {
+    assert_eq!(dst.len(), src.len(), "slices must have the same length");
+    for (dst, &src) in dst.iter().zip(src) {
+        dst.set(src);
+    }
+}
+
+/// Copies the relaxed bytes `src` into `dst`, one relaxed load per byte.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn copy_from_relaxed(dst: &mut [u8], src: &[RelaxedU8]) {
+    assert_eq!(dst.len(), src.len(), "slices must have the same length");
+    for (dst, src) in dst.iter_mut().zip(src) {
+        *dst = src.get();
+    }
+}
+
+/// Copies `src` into the relaxed words `dst`, one relaxed store per four bytes.
+///
+/// The bytes are stored in native byte order, so the words hold the same memory layout as `src`.
+/// Each word is stored atomically, so readers of a word never see a mix of two copies.
+///
+/// # Panics
+///
+/// Panics if `src` isn't four times as long as `dst`.
+///
+/// ```
+/// use relaxed::{copy_from_relaxed_words, copy_to_relaxed_words, RelaxedU32};
+///
+/// static SHADOW: [RelaxedU32; 2] = [const { RelaxedU32::new(0) }; 2];
+///
+/// copy_to_relaxed_words(&SHADOW, b"pingpong");
+/// assert_eq!(SHADOW[1].get(), u32::from_ne_bytes(*b"pong"));
+///
+/// let mut frame = [0; 8];
+/// copy_from_relaxed_words(&mut frame, &SHADOW);
+/// assert_eq!(&frame, b"pingpong");
+/// ```
+pub fn copy_to_relaxed_words(dst: &[RelaxedU32], src: &[u8]) {
+    assert_eq!(
+        dst.len() * 4,
+        src.len(),
+        "src must have four bytes per word"
+    );
+    for (dst, src) in dst.iter().zip(src.chunks_exact(4)) {
+        dst.set(u32::from_ne_bytes([src[0], src[1], src[2], src[3]]));
+    }
+}
+
+/// Copies the relaxed words `src` into `dst`, one relaxed load per four bytes.
+///
+/// # Panics
+///
+/// Panics if `dst` isn't four times as long as `src`.
+pub fn copy_from_relaxed_words(dst: &mut [u8], src: &[RelaxedU32]) {
+    assert_eq!(
+        dst.len(),
+        src.len() * 4,
+        "dst must have four bytes per word"
+    );
+    for (dst, src) in dst.chunks_exact_mut(4).zip(src) {
+        dst.copy_from_slice(&src.get().to_ne_bytes());
+    }
+}
@@ -0,0 +1,98 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::seqlock::SeqLock;
+use crate::{RelaxedF32, RelaxedI32};
+
+/// A raw sensor reading together with a runtime adjustable offset and gain.
+///
+/// The corrected value is `raw * gain + offset`.
+/// The offset and gain are guarded by a sequence lock,
+/// so [`get`](Self::get) never combines the offset of one calibration with the gain of another.
+///
+/// ```
+/// use relaxed::RelaxedCalibrated;
+///
+/// static TEMPERATURE: RelaxedCalibrated = RelaxedCalibrated::new(0.0, 1.0);
+///
+/// TEMPERATURE.set_raw(250);
+/// assert_eq!(TEMPERATURE.get(), 250.0);
+///
+/// TEMPERATURE.calibrate(-40.0, 0.5);
+/// assert_eq!(TEMPERATURE.calibration(), (-40.0, 0.5));
+/// assert_eq!(TEMPERATURE.get(), 85.0);
+/// ```
+pub struct RelaxedCalibrated {
+    raw: RelaxedI32,
+    offset: RelaxedF32,
+    gain: RelaxedF32,
+    lock: SeqLock,
+}
+
+impl RelaxedCalibrated {
+    /// Create a new value with a raw reading of zero.
+    #[inline(always)]
+    pub const fn new(offset: f32, gain: f32) -> Self {
+        RelaxedCalibrated {
+            raw: RelaxedI32::new(0),
+            offset: RelaxedF32::new(offset),
+            gain: RelaxedF32::new(gain),
+            lock: SeqLock::new(),
+        }
+    }
+
+    /// Stores a new raw reading.
+    #[inline(always)]
+    pub fn set_raw(&self, raw: i32) {
+        self.raw.set(raw)
+    }
+    /// Returns the raw reading.
+    #[inline(always)]
+    pub fn raw(&self) -> i32 {
+        self.raw.get()
+    }
+    /// Returns the corrected reading.
+    pub fn get(&self) -> f32 {
+        let (offset, gain) = self.calibration();
+        self.raw() as f32 * gain + offset
+    }
+    /// Returns the `(offset, gain)` pair.
+    pub fn calibration(&self) -> (f32, f32) {
+        self.lock.read(|| (self.offset.get(), self.gain.get()))
+    }
+    /// Replaces the offset and gain together.
+    pub fn calibrate(&self, offset: f32, gain: f32) {
+        self.lock.write(|| {
+            self.offset.set(offset);
+            self.gain.set(gain);
+        })
+    }
+}
+
+impl Debug for RelaxedCalibrated {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (offset, gain) = self.calibration();
+        f.debug_struct("RelaxedCalibrated")
+            .field("raw", &self.raw())
+            .field("offset", &offset)
+            .field("gain", &gain)
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RelaxedCalibrated {
+    /// Serializes the raw reading, offset and gain as a struct.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let (offset, gain) = self.calibration();
+        let mut state = serializer.serialize_struct("RelaxedCalibrated", 3)?;
+        state.serialize_field("raw", &self.raw())?;
+        state.serialize_field("offset", &offset)?;
+        state.serialize_field("gain", &gain)?;
+        state.end()
+    }
+}
@@ -0,0 +1,119 @@
+use core::fmt::{Debug, Formatter};
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+
+use crate::RelaxedU32;
+
+/// The most recently registered call site, each linking to the one registered before.
+static HEAD: AtomicPtr<CallSite> = AtomicPtr::new(ptr::null_mut());
+
+/// The hidden static behind every [`count_here!`](crate::count_here) invocation.
+///
+/// A call site registers itself in a global list the first time it is hit,
+/// so only call sites that ran at least once show up in [`call_sites`].
+pub struct CallSite {
+    file: &'static str,
+    line: u32,
+    module_path: &'static str,
+    count: RelaxedU32,
+    registered: AtomicBool,
+    next: AtomicPtr<CallSite>,
+}
+
+impl CallSite {
+    #[doc(hidden)]
+    pub const fn new(file: &'static str, line: u32, module_path: &'static str) -> Self {
+        CallSite {
+            file,
+            line,
+            module_path,
+            count: RelaxedU32::new(0),
+            registered: AtomicBool::new(false),
+            next: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Counts one execution, returning the new count.
+    #[doc(hidden)]
+    pub fn hit(&'static self) -> u32 {
+        if !self.registered.load(Ordering::Relaxed)
+            && !self.registered.swap(true, Ordering::Relaxed)
+        {
+            self.register();
+        }
+        self.count.fetch_add_wrapping(1).wrapping_add(1)
+    }
+
+    fn register(&'static self) {
+        let this = self as *const CallSite as *mut CallSite;
+        let mut head = HEAD.load(Ordering::Relaxed);
+        loop {
+            self.next.store(head, Ordering::Relaxed);
+            match HEAD.compare_exchange_weak(head, this, Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => return,
+                Err(actual) => head = actual,
+            }
+        }
+    }
+
+    /// Returns the source file of the call site.
+    #[inline(always)]
+    pub fn file(&self) -> &'static str {
+        self.file
+    }
+    /// Returns the source line of the call site.
+    #[inline(always)]
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+    /// Returns the module path of the call site.
+    #[inline(always)]
+    pub fn module_path(&self) -> &'static str {
+        self.module_path
+    }
+    /// Returns how often the call site ran.
+    #[inline(always)]
+    pub fn count(&self) -> u32 {
+        self.count.get()
+    }
+    /// Resets the count to zero, returning the previous count.
+    #[inline(always)]
+    pub fn reset(&self) -> u32 {
+        self.count.swap(0)
+    }
+}
+
+impl Debug for CallSite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CallSite")
+            .field("file", &self.file)
+            .field("line", &self.line)
+            .field("count", &self.count())
+            .finish()
+    }
+}
+
+/// Returns an iterator over every [`count_here!`](crate::count_here) call site that ran so far,
+/// most recently registered first.
+pub fn call_sites() -> CallSites {
+    CallSites {
+        next: HEAD.load(Ordering::Acquire),
+    }
+}
+
+/// An iterator over the registered call sites, returned by [`call_sites`].
+#[derive(Clone)]
+pub struct CallSites {
+    next: *const CallSite,
+}
+
+impl Iterator for CallSites {
+    type Item = &'static CallSite;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: registered call sites are statics
+        let site = unsafe { self.next.as_ref()? };
+        self.next = site.next.load(Ordering::Relaxed);
+        Some(site)
+    }
+}
@@ -0,0 +1,86 @@
+use core::fmt::{Debug, Display, Formatter};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+const fn pack(val: u16) -> u32 {
+    (val as u32) << 16 | !val as u32
+}
+
+const fn unpack(bits: u32) -> Result<u16, CorruptionError> {
+    let (val, complement) = ((bits >> 16) as u16, bits as u16);
+    if val == !complement {
+        Ok(val)
+    } else {
+        Err(CorruptionError { bits })
+    }
+}
+
+/// A `u16` stored together with its bitwise complement, to detect memory corruption.
+///
+/// Both halves live in a single atomic word, so a concurrent store can't tear them apart,
+/// and a flipped bit in either half makes [`get`](Self::get) fail.
+///
+/// ```
+/// use relaxed::RelaxedCheckedU16;
+///
+/// static SETPOINT: RelaxedCheckedU16 = RelaxedCheckedU16::new(500);
+///
+/// SETPOINT.set(750);
+/// assert_eq!(SETPOINT.get(), Ok(750));
+/// ```
+#[repr(transparent)]
+pub struct RelaxedCheckedU16(AtomicU32);
+
+impl RelaxedCheckedU16 {
+    /// Create a new value.
+    #[inline(always)]
+    pub const fn new(val: u16) -> Self {
+        RelaxedCheckedU16(AtomicU32::new(pack(val)))
+    }
+
+    /// Performs an atomic load with relaxed ordering, checking the complement.
+    #[inline(always)]
+    pub fn get(&self) -> Result<u16, CorruptionError> {
+        unpack(self.0.load(Ordering::Relaxed))
+    }
+    /// Performs an atomic store with relaxed ordering.
+    #[inline(always)]
+    pub fn set(&self, val: u16) {
+        self.0.store(pack(val), Ordering::Relaxed)
+    }
+    /// Stores a value, returning the previous value after checking its complement.
+    #[inline(always)]
+    pub fn swap(&self, val: u16) -> Result<u16, CorruptionError> {
+        unpack(self.0.swap(pack(val), Ordering::Relaxed))
+    }
+}
+
+impl Default for RelaxedCheckedU16 {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Debug for RelaxedCheckedU16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl From<u16> for RelaxedCheckedU16 {
+    fn from(val: u16) -> Self {
+        Self::new(val)
+    }
+}
+
+/// Error returned when a [`RelaxedCheckedU16`] no longer matches its complement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CorruptionError {
+    /// The corrupted word, the value in the high half and the complement in the low half.
+    pub bits: u32,
+}
+
+impl Display for CorruptionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "corrupted value {:#010x}", self.bits)
+    }
+}
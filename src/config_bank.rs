@@ -0,0 +1,160 @@
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+use crate::RelaxedF32;
+
+/// Keys addressing the parameters of a [`ConfigBank`], usually a fieldless enum.
+pub trait BankKey: Copy {
+    /// Returns the index of the parameter, which must be less than the size of the bank.
+    fn index(self) -> usize;
+}
+
+/// A bank of `N` tunable `f32` parameters addressed by the key `E`.
+///
+/// ```
+/// use relaxed::{BankKey, ConfigBank};
+///
+/// #[derive(Clone, Copy)]
+/// enum Gain {
+///     P,
+///     I,
+///     D,
+/// }
+///
+/// impl BankKey for Gain {
+///     fn index(self) -> usize {
+///         self as usize
+///     }
+/// }
+///
+/// static GAINS: ConfigBank<Gain, 3> = ConfigBank::new([1.0, 0.1, 0.0]);
+///
+/// // from a debugger or serial console:
+/// GAINS.set(Gain::D, 0.05);
+///
+/// // in the control loop:
+/// assert_eq!(GAINS.get(Gain::D), 0.05);
+/// assert_eq!(GAINS.snapshot(), [1.0, 0.1, 0.05]);
+/// ```
+pub struct ConfigBank<E, const N: usize> {
+    cells: [RelaxedF32; N],
+    defaults: [f32; N],
+    _key: PhantomData<fn(E)>,
+}
+
+impl<E, const N: usize> ConfigBank<E, N> {
+    /// Create a new bank with every parameter set to its default.
+    pub const fn new(defaults: [f32; N]) -> Self {
+        let mut cells = [const { RelaxedF32::new(0.0) }; N];
+        let mut i = 0;
+        while i < N {
+            cells[i] = RelaxedF32::new(defaults[i]);
+            i += 1;
+        }
+        ConfigBank {
+            cells,
+            defaults,
+            _key: PhantomData,
+        }
+    }
+
+    /// Returns the default values the bank was created with.
+    pub fn defaults(&self) -> &[f32; N] {
+        &self.defaults
+    }
+    /// Returns the parameters as individual relaxed values.
+    pub fn cells(&self) -> &[RelaxedF32; N] {
+        &self.cells
+    }
+
+    /// Loads every parameter with relaxed ordering.
+    ///
+    /// The parameters are loaded one after another, so the snapshot is not atomic as a whole.
+    pub fn snapshot(&self) -> [f32; N] {
+        core::array::from_fn(|i| self.cells[i].get())
+    }
+    /// Stores every parameter with relaxed ordering.
+    pub fn restore(&self, values: &[f32; N]) {
+        for (cell, &val) in self.cells.iter().zip(values) {
+            cell.set(val);
+        }
+    }
+    /// Sets every parameter back to its default.
+    pub fn reset(&self) {
+        self.restore(&self.defaults)
+    }
+}
+
+impl<E: BankKey, const N: usize> ConfigBank<E, N> {
+    /// Returns the parameter as a relaxed value.
+    ///
+    /// # Panics
+    /// Panics if the index of `key` is out of bounds.
+    #[inline(always)]
+    pub fn cell(&self, key: E) -> &RelaxedF32 {
+        &self.cells[key.index()]
+    }
+    /// Performs an atomic load with relaxed ordering.
+    ///
+    /// # Panics
+    /// Panics if the index of `key` is out of bounds.
+    #[inline(always)]
+    pub fn get(&self, key: E) -> f32 {
+        self.cell(key).get()
+    }
+    /// Performs an atomic store with relaxed ordering.
+    ///
+    /// # Panics
+    /// Panics if the index of `key` is out of bounds.
+    #[inline(always)]
+    pub fn set(&self, key: E, val: f32) {
+        self.cell(key).set(val)
+    }
+    /// Returns the default value of the parameter.
+    ///
+    /// # Panics
+    /// Panics if the index of `key` is out of bounds.
+    pub fn default_of(&self, key: E) -> f32 {
+        self.defaults[key.index()]
+    }
+    /// Sets the parameter back to its default.
+    ///
+    /// # Panics
+    /// Panics if the index of `key` is out of bounds.
+    pub fn reset_one(&self, key: E) {
+        self.set(key, self.default_of(key))
+    }
+}
+
+impl<E, const N: usize> Debug for ConfigBank<E, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.cells.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<E, const N: usize> serde::Serialize for ConfigBank<E, N> {
+    /// Serializes the current values as a tuple of `N` floats.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for cell in &self.cells {
+            tuple.serialize_element(&cell.get())?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<E, const N: usize> ConfigBank<E, N> {
+    /// Encodes the current values with postcard into `buf`, returning the used part of the buffer.
+    ///
+    /// The wire format is `N` little endian `f32`s, without a length prefix.
+    pub fn encode_into<'b>(&self, buf: &'b mut [u8]) -> postcard::Result<&'b mut [u8]> {
+        postcard::to_slice(self, buf)
+    }
+}
@@ -0,0 +1,306 @@
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+use crate::{RelaxedBool, RelaxedU32};
+
+/// An event counter that detects overflow instead of silently wrapping around.
+///
+/// On overflow the count saturates at `u32::MAX` and a flag is latched
+/// until the counter is [`reset`](Self::reset).
+///
+/// ```
+/// use relaxed::RelaxedCheckedCounter;
+///
+/// static FAULTS: RelaxedCheckedCounter = RelaxedCheckedCounter::new();
+///
+/// assert_eq!(FAULTS.increment(), Some(1));
+/// assert_eq!(FAULTS.add(u32::MAX), None);
+/// assert!(FAULTS.overflowed());
+/// assert_eq!(FAULTS.get(), u32::MAX);
+/// ```
+#[derive(Default)]
+pub struct RelaxedCheckedCounter {
+    count: RelaxedU32,
+    overflowed: RelaxedBool,
+}
+
+impl RelaxedCheckedCounter {
+    /// Create a new counter at zero.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        RelaxedCheckedCounter {
+            count: RelaxedU32::new(0),
+            overflowed: RelaxedBool::new(false),
+        }
+    }
+
+    /// Adds one, returning the new count or `None` if the counter overflowed.
+    #[inline(always)]
+    pub fn increment(&self) -> Option<u32> {
+        self.add(1)
+    }
+    /// Adds `n`, returning the new count or `None` if the counter overflowed.
+    pub fn add(&self, n: u32) -> Option<u32> {
+        let previous = self.count.get_and_update(|count| count.saturating_add(n));
+        let new = previous.checked_add(n);
+        if new.is_none() {
+            self.overflowed.set(true);
+        }
+        new
+    }
+    /// Returns the current count.
+    #[inline(always)]
+    pub fn get(&self) -> u32 {
+        self.count.get()
+    }
+    /// Returns whether the counter overflowed since the last reset.
+    #[inline(always)]
+    pub fn overflowed(&self) -> bool {
+        self.overflowed.get()
+    }
+    /// Resets the count to zero and clears the overflow flag,
+    /// returning the previous count or `None` if it had overflowed.
+    pub fn reset(&self) -> Option<u32> {
+        let count = self.count.swap(0);
+        if self.overflowed.swap(false) {
+            None
+        } else {
+            Some(count)
+        }
+    }
+}
+
+impl Debug for RelaxedCheckedCounter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RelaxedCheckedCounter")
+            .field("count", &self.get())
+            .field("overflowed", &self.overflowed())
+            .finish()
+    }
+}
+
+/// An event counter that reports the number of events since the last report.
+///
+/// The running total wraps around, deltas are computed with wrapping arithmetic,
+/// so they stay correct as long as fewer than `2^32` events happen between two reports.
+/// Meant for a single reporting context, e.g. a periodic telemetry task.
+///
+/// ```
+/// use relaxed::RelaxedDeltaCounter;
+///
+/// static RX_PACKETS: RelaxedDeltaCounter = RelaxedDeltaCounter::new();
+///
+/// RX_PACKETS.increment();
+/// RX_PACKETS.add(2);
+/// assert_eq!(RX_PACKETS.take_delta(), 3);
+/// assert_eq!(RX_PACKETS.take_delta(), 0);
+/// assert_eq!(RX_PACKETS.total(), 3);
+/// ```
+#[derive(Default)]
+pub struct RelaxedDeltaCounter {
+    total: RelaxedU32,
+    reported: RelaxedU32,
+}
+
+impl RelaxedDeltaCounter {
+    /// Create a new counter at zero.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        RelaxedDeltaCounter {
+            total: RelaxedU32::new(0),
+            reported: RelaxedU32::new(0),
+        }
+    }
+
+    /// Adds one event.
+    #[inline(always)]
+    pub fn increment(&self) {
+        self.add(1)
+    }
+    /// Adds `n` events.
+    #[inline(always)]
+    pub fn add(&self, n: u32) {
+        self.total.fetch_add_wrapping(n);
+    }
+    /// Returns the wrapping total of all events.
+    #[inline(always)]
+    pub fn total(&self) -> u32 {
+        self.total.get()
+    }
+    /// Returns the number of events since the last call and rebases the counter.
+    pub fn take_delta(&self) -> u32 {
+        let total = self.total.get();
+        total.wrapping_sub(self.reported.swap(total))
+    }
+}
+
+impl Debug for RelaxedDeltaCounter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RelaxedDeltaCounter")
+            .field("total", &self.total())
+            .field("reported", &self.reported.get())
+            .finish()
+    }
+}
+
+/// How a [`RelaxedCounter`] behaves when an addition overflows `u32`.
+pub trait OverflowPolicy {
+    /// The result of an addition, e.g. the new count.
+    type Output;
+
+    /// Adds `n` to `count`, returning the count to store, or `None` to leave it unchanged,
+    /// and the result reported to the caller.
+    fn add(count: u32, n: u32) -> (Option<u32>, Self::Output);
+}
+
+/// Wraps around to zero on overflow. Additions return the new count.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Wrapping;
+
+/// Stays at `u32::MAX` on overflow. Additions return the new count.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Saturating;
+
+/// Rejects additions that would overflow, leaving the count unchanged.
+/// Additions return the new count, or `None` if rejected.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Checked;
+
+impl OverflowPolicy for Wrapping {
+    type Output = u32;
+
+    #[inline(always)]
+    fn add(count: u32, n: u32) -> (Option<u32>, u32) {
+        let new = count.wrapping_add(n);
+        (Some(new), new)
+    }
+}
+
+impl OverflowPolicy for Saturating {
+    type Output = u32;
+
+    #[inline(always)]
+    fn add(count: u32, n: u32) -> (Option<u32>, u32) {
+        let new = count.saturating_add(n);
+        ((new != count).then_some(new), new)
+    }
+}
+
+impl OverflowPolicy for Checked {
+    type Output = Option<u32>;
+
+    #[inline(always)]
+    fn add(count: u32, n: u32) -> (Option<u32>, Option<u32>) {
+        let new = count.checked_add(n);
+        (new, new)
+    }
+}
+
+/// An event counter whose overflow behavior is chosen by the [`OverflowPolicy`] `P`.
+///
+/// ```
+/// use relaxed::{Checked, RelaxedCounter, Saturating, Wrapping};
+///
+/// static SEQUENCE: RelaxedCounter<Wrapping> = RelaxedCounter::new(u32::MAX);
+/// static RETRIES: RelaxedCounter<Saturating> = RelaxedCounter::new(u32::MAX - 1);
+/// static CREDITS: RelaxedCounter<Checked> = RelaxedCounter::new(u32::MAX - 1);
+///
+/// assert_eq!(SEQUENCE.increment(), 0);
+///
+/// assert_eq!(RETRIES.add(5), u32::MAX);
+///
+/// assert_eq!(CREDITS.increment(), Some(u32::MAX));
+/// assert_eq!(CREDITS.increment(), None);
+/// assert_eq!(CREDITS.get(), u32::MAX);
+/// ```
+pub struct RelaxedCounter<P: OverflowPolicy = Wrapping> {
+    count: RelaxedU32,
+    policy: PhantomData<fn() -> P>,
+}
+
+impl<P: OverflowPolicy> RelaxedCounter<P> {
+    /// Create a new counter at `count`.
+    #[inline(always)]
+    pub const fn new(count: u32) -> Self {
+        RelaxedCounter {
+            count: RelaxedU32::new(count),
+            policy: PhantomData,
+        }
+    }
+
+    /// Adds one, returning the result of the policy.
+    #[inline(always)]
+    pub fn increment(&self) -> P::Output {
+        self.add(1)
+    }
+    /// Adds `n`, returning the result of the policy.
+    pub fn add(&self, n: u32) -> P::Output {
+        let mut output = None;
+        let _ = self.count.fetch_update(|count| {
+            let (new, result) = P::add(count, n);
+            output = Some(result);
+            new
+        });
+        output.expect("fetch_update calls the closure at least once")
+    }
+    /// Returns the current count.
+    #[inline(always)]
+    pub fn get(&self) -> u32 {
+        self.count.get()
+    }
+    /// Resets the count to zero, returning the previous count.
+    #[inline(always)]
+    pub fn reset(&self) -> u32 {
+        self.count.swap(0)
+    }
+}
+
+impl<P: OverflowPolicy> Default for RelaxedCounter<P> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<P: OverflowPolicy> Debug for RelaxedCounter<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RelaxedCounter").field(&self.get()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RelaxedCheckedCounter {
+    /// Serializes the counter as a struct of the count and the overflow flag.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("RelaxedCheckedCounter", 2)?;
+        state.serialize_field("count", &self.get())?;
+        state.serialize_field("overflowed", &self.overflowed())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RelaxedDeltaCounter {
+    /// Serializes the wrapping total, without taking a delta.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.total().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P: OverflowPolicy> serde::Serialize for RelaxedCounter<P> {
+    /// Serializes the current count.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
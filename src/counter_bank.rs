@@ -0,0 +1,158 @@
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+use crate::{BankKey, RelaxedU32};
+
+/// A bank of `N` named event counters addressed by the key `E`,
+/// usually declared with the [`irq_counters!`](crate::irq_counters!) macro.
+///
+/// The counters wrap around on overflow.
+pub struct CounterBank<E, const N: usize> {
+    names: [&'static str; N],
+    counts: [RelaxedU32; N],
+    _key: PhantomData<fn(E)>,
+}
+
+impl<E, const N: usize> CounterBank<E, N> {
+    /// Create a new bank with every counter at zero.
+    pub const fn new(names: [&'static str; N]) -> Self {
+        CounterBank {
+            names,
+            counts: [const { RelaxedU32::new(0) }; N],
+            _key: PhantomData,
+        }
+    }
+
+    /// Returns the names of the counters.
+    pub fn names(&self) -> &[&'static str; N] {
+        &self.names
+    }
+    /// Returns the counters as individual relaxed values.
+    pub fn counters(&self) -> &[RelaxedU32; N] {
+        &self.counts
+    }
+    /// Returns an iterator over the name and current count of every counter.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u32)> + '_ {
+        self.names
+            .iter()
+            .zip(&self.counts)
+            .map(|(&name, count)| (name, count.get()))
+    }
+    /// Loads every counter with relaxed ordering.
+    ///
+    /// The counters are loaded one after another, so the snapshot is not atomic as a whole.
+    pub fn snapshot(&self) -> [u32; N] {
+        core::array::from_fn(|i| self.counts[i].get())
+    }
+    /// Sets every counter back to zero.
+    pub fn reset(&self) {
+        for count in &self.counts {
+            count.set(0);
+        }
+    }
+}
+
+impl<E: BankKey, const N: usize> CounterBank<E, N> {
+    /// Adds one to the counter of `key`.
+    ///
+    /// # Panics
+    /// Panics if the index of `key` is out of bounds.
+    #[inline(always)]
+    pub fn increment(&self, key: E) {
+        self.add(key, 1)
+    }
+    /// Adds `n` to the counter of `key`.
+    ///
+    /// # Panics
+    /// Panics if the index of `key` is out of bounds.
+    #[inline(always)]
+    pub fn add(&self, key: E, n: u32) {
+        self.counts[key.index()].fetch_add_wrapping(n);
+    }
+    /// Performs an atomic load with relaxed ordering.
+    ///
+    /// # Panics
+    /// Panics if the index of `key` is out of bounds.
+    #[inline(always)]
+    pub fn get(&self, key: E) -> u32 {
+        self.counts[key.index()].get()
+    }
+    /// Returns the name of the counter of `key`.
+    ///
+    /// # Panics
+    /// Panics if the index of `key` is out of bounds.
+    pub fn name_of(&self, key: E) -> &'static str {
+        self.names[key.index()]
+    }
+}
+
+impl<E, const N: usize> Debug for CounterBank<E, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Declares a fieldless enum of interrupts and a static [`CounterBank`](crate::CounterBank)
+/// with one counter per variant, named after the variant.
+///
+/// The enum derives `Clone` and `Copy` and implements [`BankKey`](crate::BankKey).
+///
+/// ```
+/// use relaxed::irq_counters;
+///
+/// irq_counters! {
+///     #[derive(Debug)]
+///     pub enum Irq {
+///         Uart0,
+///         Timer1,
+///     }
+///     pub static IRQ_COUNTS;
+/// }
+///
+/// // in the interrupt handlers:
+/// IRQ_COUNTS.increment(Irq::Timer1);
+///
+/// // in the diagnostics task:
+/// let counts: Vec<_> = IRQ_COUNTS.iter().collect();
+/// assert_eq!(counts, [("Uart0", 0), ("Timer1", 1)]);
+/// ```
+#[macro_export]
+macro_rules! irq_counters {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $key:ident {
+            $($(#[$variant_attr:meta])* $variant:ident),* $(,)?
+        }
+        $(#[$static_attr:meta])*
+        $static_vis:vis static $name:ident;
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy)]
+        $vis enum $key {
+            $($(#[$variant_attr])* $variant),*
+        }
+
+        impl $crate::BankKey for $key {
+            fn index(self) -> usize {
+                self as usize
+            }
+        }
+
+        $(#[$static_attr])*
+        $static_vis static $name: $crate::CounterBank<
+            $key,
+            { [$(::core::stringify!($variant)),*].len() },
+        > = $crate::CounterBank::new([$(::core::stringify!($variant)),*]);
+    };
+}
+
+#[cfg(feature = "serde")]
+impl<E, const N: usize> serde::Serialize for CounterBank<E, N> {
+    /// Serializes the counters as a map from their names to their counts.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
@@ -0,0 +1,83 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::RelaxedOption;
+
+/// An absolute deadline in ticks of a free-running `u32` timer, or disarmed.
+///
+/// Comparisons are wraparound-correct as long as the deadline is less than
+/// `2^31` ticks away from `now`.
+///
+/// ```
+/// use relaxed::RelaxedDeadline;
+///
+/// static TIMEOUT: RelaxedDeadline = RelaxedDeadline::disarmed();
+///
+/// // the timer is about to wrap around
+/// let now = u32::MAX - 5;
+/// TIMEOUT.arm_after(now, 10);
+///
+/// assert!(!TIMEOUT.expired(now));
+/// assert_eq!(TIMEOUT.remaining(now), Some(10));
+/// assert!(TIMEOUT.expired(now.wrapping_add(10)));
+///
+/// TIMEOUT.disarm();
+/// assert!(!TIMEOUT.expired(now.wrapping_add(10)));
+/// ```
+#[derive(Default)]
+pub struct RelaxedDeadline(RelaxedOption<u32>);
+
+impl RelaxedDeadline {
+    /// Create a new disarmed deadline.
+    #[inline(always)]
+    pub const fn disarmed() -> Self {
+        RelaxedDeadline(RelaxedOption::none())
+    }
+
+    /// Arms the deadline at the absolute tick `at`.
+    ///
+    /// `u32::MAX` marks a disarmed deadline, so a deadline at that tick fires one tick later.
+    #[inline(always)]
+    pub fn arm(&self, at: u32) {
+        let at = if at == u32::MAX { 0 } else { at };
+        let _ = self.0.set(Some(at));
+    }
+    /// Arms the deadline `budget` ticks after `now`.
+    #[inline(always)]
+    pub fn arm_after(&self, now: u32, budget: u32) {
+        self.arm(now.wrapping_add(budget))
+    }
+    /// Disarms the deadline.
+    #[inline(always)]
+    pub fn disarm(&self) {
+        let _ = self.0.set(None);
+    }
+    /// Returns the absolute tick the deadline is armed at.
+    #[inline(always)]
+    pub fn get(&self) -> Option<u32> {
+        self.0.get()
+    }
+    /// Returns `true` if the deadline is armed.
+    #[inline(always)]
+    pub fn is_armed(&self) -> bool {
+        self.0.is_some()
+    }
+    /// Returns `true` if the deadline is armed and `now` is at or past it.
+    #[inline(always)]
+    pub fn expired(&self, now: u32) -> bool {
+        self.get()
+            .is_some_and(|at| now.wrapping_sub(at) as i32 >= 0)
+    }
+    /// Returns the number of ticks left until the deadline, zero once it expired,
+    /// or `None` if it is disarmed.
+    #[inline(always)]
+    pub fn remaining(&self, now: u32) -> Option<u32> {
+        self.get()
+            .map(|at| (at.wrapping_sub(now) as i32).max(0) as u32)
+    }
+}
+
+impl Debug for RelaxedDeadline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RelaxedDeadline").field(&self.get()).finish()
+    }
+}
@@ -0,0 +1,27 @@
+use core::time::Duration;
+
+use crate::RelaxedDuration;
+
+/// Converts to whole nanoseconds, saturating at `u64::MAX` (roughly 584 years).
+pub(crate) const fn to_nanos(val: Duration) -> u64 {
+    let nanos = val.as_nanos();
+    if nanos > u64::MAX as u128 {
+        u64::MAX
+    } else {
+        nanos as u64
+    }
+}
+
+pub(crate) const fn from_nanos(nanos: u64) -> Duration {
+    Duration::from_nanos(nanos)
+}
+
+impl RelaxedDuration {
+    /// Adds to the current value, saturating at `u64::MAX` nanoseconds,
+    /// returning the previous value.
+    #[inline(always)]
+    pub fn saturating_add(&self, val: Duration) -> Duration {
+        let val = to_nanos(val);
+        self.get_and_update(|current| from_nanos(to_nanos(current).saturating_add(val)))
+    }
+}
@@ -0,0 +1,92 @@
+//! Interop with [`embassy_sync`].
+//!
+//! ```
+//! use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+//! use relaxed::embassy::RelaxedSignal;
+//! use relaxed::RelaxedU32;
+//!
+//! static SPEED: RelaxedSignal<CriticalSectionRawMutex, RelaxedU32> =
+//!     RelaxedSignal::new(RelaxedU32::new(0));
+//!
+//! // in an interrupt or another task:
+//! SPEED.set(1200);
+//!
+//! // in a task:
+//! # async fn task() {
+//! let speed = SPEED.wait().await;
+//! # }
+//! assert_eq!(SPEED.get(), 1200);
+//! ```
+
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::signal::Signal;
+
+use crate::RelaxedValue;
+
+/// A relaxed value that wakes a waiting task whenever it is set.
+///
+/// Reading the value never blocks. Only the notification goes through an
+/// [`embassy_sync::signal::Signal`], so a task that is woken always reads the latest value.
+pub struct RelaxedSignal<M: RawMutex, R> {
+    value: R,
+    signal: Signal<M, ()>,
+}
+
+impl<M: RawMutex, R: RelaxedValue> RelaxedSignal<M, R> {
+    /// Create a new value.
+    pub const fn new(value: R) -> Self {
+        RelaxedSignal {
+            value,
+            signal: Signal::new(),
+        }
+    }
+
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> R::Value {
+        self.value.get()
+    }
+
+    /// Performs an atomic store with relaxed ordering and wakes the waiting task.
+    pub fn set(&self, val: R::Value) {
+        self.value.set(val);
+        self.signal.signal(());
+    }
+
+    /// Waits until the value is set and returns the current value.
+    ///
+    /// Returns immediately if the value was set since the last call.
+    pub async fn wait(&self) -> R::Value {
+        self.signal.wait().await;
+        self.value.get()
+    }
+
+    /// Returns the current value if it was set since the last call to [`wait`](Self::wait)
+    /// or [`try_take`](Self::try_take).
+    pub fn try_take(&self) -> Option<R::Value> {
+        self.signal.try_take().map(|()| self.value.get())
+    }
+
+    /// Returns whether the value was set since the last call to [`wait`](Self::wait).
+    pub fn signaled(&self) -> bool {
+        self.signal.signaled()
+    }
+
+    /// Clears a pending notification without touching the value.
+    pub fn reset(&self) {
+        self.signal.reset()
+    }
+
+    /// Returns the underlying relaxed value.
+    ///
+    /// Setting it directly does not wake the waiting task.
+    pub fn inner(&self) -> &R {
+        &self.value
+    }
+}
+
+impl<M: RawMutex, R: RelaxedValue + Default> Default for RelaxedSignal<M, R> {
+    fn default() -> Self {
+        Self::new(R::default())
+    }
+}
@@ -0,0 +1,116 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::RelaxedU32;
+
+/// Marks a slot that holds no entry, so `(0xffff, 0xffff)` can't be logged.
+const EMPTY: u32 = u32::MAX;
+
+/// A lock-free trace buffer of `(id, data)` entries, each packed into a single atomic word.
+///
+/// Any context may [`push`](Self::push) by claiming the next slot, overwriting the oldest
+/// entry once the log is full. A reader [`drain`](Self::drain)s the entries in slot order,
+/// e.g. after a crash or from a debugger.
+///
+/// ```
+/// use relaxed::RelaxedEventLog;
+///
+/// static EVENTS: RelaxedEventLog<8> = RelaxedEventLog::new();
+///
+/// EVENTS.push(1, 100);
+/// EVENTS.push(2, 200);
+///
+/// let mut entries = [(0, 0); 8];
+/// let n = EVENTS.drain(&mut entries);
+/// assert_eq!(&entries[..n], [(1, 100), (2, 200)]);
+/// assert_eq!(EVENTS.drain(&mut entries), 0);
+/// ```
+pub struct RelaxedEventLog<const N: usize> {
+    slots: [RelaxedU32; N],
+    next: RelaxedU32,
+}
+
+impl<const N: usize> RelaxedEventLog<N> {
+    /// Create a new empty log.
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                N > 0 && N <= u32::MAX as usize,
+                "N must be non-zero and fit a u32"
+            )
+        };
+        RelaxedEventLog {
+            slots: [const { RelaxedU32::new(EMPTY) }; N],
+            next: RelaxedU32::new(0),
+        }
+    }
+
+    /// Appends an entry, overwriting the oldest one if the log is full.
+    ///
+    /// The entry `(0xffff, 0xffff)` marks empty slots, so it is ignored
+    /// without claiming a slot.
+    ///
+    /// ```
+    /// use relaxed::RelaxedEventLog;
+    ///
+    /// let events = RelaxedEventLog::<4>::new();
+    /// events.push(0xffff, 0xffff);
+    /// assert!(events.is_empty());
+    /// ```
+    #[inline]
+    pub fn push(&self, id: u16, data: u16) {
+        let bits = (id as u32) << 16 | data as u32;
+        if bits == EMPTY {
+            return;
+        }
+        let slot = self.next.fetch_add_mod(1, N as u32) as usize;
+        self.slots[slot].set(bits);
+    }
+    /// Moves the entries into `buf` in slot order, starting at the oldest slot,
+    /// and returns how many were written.
+    ///
+    /// Stops once `buf` is full, leaving the remaining entries in the log.
+    pub fn drain(&self, buf: &mut [(u16, u16)]) -> usize {
+        let start = self.next.get() as usize;
+        let mut written = 0;
+        for i in 0..N {
+            if written == buf.len() {
+                break;
+            }
+            let bits = self.slots[(start + i) % N].swap(EMPTY);
+            if bits != EMPTY {
+                buf[written] = ((bits >> 16) as u16, bits as u16);
+                written += 1;
+            }
+        }
+        written
+    }
+    /// Returns the number of entries in the log.
+    pub fn len(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.get() != EMPTY).count()
+    }
+    /// Returns `true` if the log holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Removes every entry.
+    pub fn clear(&self) {
+        for slot in &self.slots {
+            slot.set(EMPTY);
+        }
+    }
+}
+
+impl<const N: usize> Default for RelaxedEventLog<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Debug for RelaxedEventLog<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RelaxedEventLog")
+            .field("len", &self.len())
+            .field("capacity", &N)
+            .finish()
+    }
+}
@@ -0,0 +1,66 @@
+use core::sync::atomic::Ordering;
+
+use fixed::types::{I16F16, U16F16};
+
+use crate::{RelaxedI16F16, RelaxedU16F16};
+
+macro_rules! fixed_impls {
+    ($name:ident: $inner:ty) => {
+        impl $name {
+            /// Adds to the current value, wrapping around on overflow, returning the previous value.
+            #[inline(always)]
+            pub fn fetch_add(&self, val: $inner) -> $inner {
+                let previous = self.0.fetch_add(val.to_bits(), Ordering::Relaxed);
+                #[cfg(feature = "trace")]
+                self.trace(previous, previous.wrapping_add(val.to_bits()));
+                <$inner>::from_bits(previous)
+            }
+            /// Subtracts from the current value, wrapping around on overflow,
+            /// returning the previous value.
+            #[inline(always)]
+            pub fn fetch_sub(&self, val: $inner) -> $inner {
+                let previous = self.0.fetch_sub(val.to_bits(), Ordering::Relaxed);
+                #[cfg(feature = "trace")]
+                self.trace(previous, previous.wrapping_sub(val.to_bits()));
+                <$inner>::from_bits(previous)
+            }
+            /// Multiplies the current value, wrapping around on overflow,
+            /// returning the previous value.
+            #[inline(always)]
+            pub fn fetch_mul(&self, val: $inner) -> $inner {
+                self.get_and_update(|current| current.wrapping_mul(val))
+            }
+            /// Adds to the current value, saturating at the numeric bounds,
+            /// returning the previous value.
+            #[inline(always)]
+            pub fn fetch_saturating_add(&self, val: $inner) -> $inner {
+                self.get_and_update(|current| current.saturating_add(val))
+            }
+            /// Subtracts from the current value, saturating at the numeric bounds,
+            /// returning the previous value.
+            #[inline(always)]
+            pub fn fetch_saturating_sub(&self, val: $inner) -> $inner {
+                self.get_and_update(|current| current.saturating_sub(val))
+            }
+            /// Stores the maximum of the current value and `val`, returning the previous value.
+            #[inline(always)]
+            pub fn fetch_max(&self, val: $inner) -> $inner {
+                let previous = self.0.fetch_max(val.to_bits(), Ordering::Relaxed);
+                #[cfg(feature = "trace")]
+                self.trace(previous, previous.max(val.to_bits()));
+                <$inner>::from_bits(previous)
+            }
+            /// Stores the minimum of the current value and `val`, returning the previous value.
+            #[inline(always)]
+            pub fn fetch_min(&self, val: $inner) -> $inner {
+                let previous = self.0.fetch_min(val.to_bits(), Ordering::Relaxed);
+                #[cfg(feature = "trace")]
+                self.trace(previous, previous.min(val.to_bits()));
+                <$inner>::from_bits(previous)
+            }
+        }
+    };
+}
+
+fixed_impls!(RelaxedI16F16: I16F16);
+fixed_impls!(RelaxedU16F16: U16F16);
@@ -0,0 +1,73 @@
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+macro_rules! fn_impls {
+    ($name:ident, $noop:ident: fn($($arg:ident: $ty:ident),*)) => {
+        /// A function pointer that always uses `Ordering::Relaxed` for access,
+        /// e.g. to change which handler an interrupt dispatches to at runtime.
+        #[repr(transparent)]
+        pub struct $name<$($ty,)* R>(AtomicPtr<()>, PhantomData<fn($($ty),*) -> R>);
+
+        fn $noop<$($ty,)* R: Default>($(_: $ty),*) -> R {
+            R::default()
+        }
+
+        impl<$($ty,)* R> $name<$($ty,)* R> {
+            /// Create a new value.
+            #[inline(always)]
+            pub const fn new(f: fn($($ty),*) -> R) -> Self {
+                $name(AtomicPtr::new(f as *mut ()), PhantomData)
+            }
+
+            /// Create a new value calling a function that does nothing and returns `R::default()`.
+            #[inline(always)]
+            pub const fn noop() -> Self
+            where
+                R: Default,
+            {
+                Self::new($noop)
+            }
+
+            /// Performs an atomic load with relaxed ordering.
+            #[inline(always)]
+            pub fn get(&self) -> fn($($ty),*) -> R {
+                let ptr = self.0.load(Ordering::Relaxed);
+                // SAFETY: the pointer always comes from a function pointer of the same type
+                unsafe { core::mem::transmute::<*mut (), fn($($ty),*) -> R>(ptr) }
+            }
+            /// Performs an atomic store with relaxed ordering.
+            #[inline(always)]
+            pub fn set(&self, f: fn($($ty),*) -> R) {
+                self.0.store(f as *mut (), Ordering::Relaxed)
+            }
+            /// Loads the function pointer with relaxed ordering and calls it.
+            #[inline(always)]
+            pub fn call(&self, $($arg: $ty),*) -> R {
+                self.get()($($arg),*)
+            }
+        }
+
+        impl<$($ty,)* R: Default> Default for $name<$($ty,)* R> {
+            fn default() -> Self {
+                Self::noop()
+            }
+        }
+
+        impl<$($ty,)* R> Debug for $name<$($ty,)* R> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl<$($ty,)* R> From<fn($($ty),*) -> R> for $name<$($ty,)* R> {
+            fn from(f: fn($($ty),*) -> R) -> Self {
+                $name::new(f)
+            }
+        }
+    };
+}
+
+fn_impls!(RelaxedFn0, noop0: fn());
+fn_impls!(RelaxedFn1, noop1: fn(a: A));
+fn_impls!(RelaxedFn2, noop2: fn(a: A, b: B));
@@ -0,0 +1,137 @@
+//! Relaxed cells for [`fugit`]'s 32-bit time types, counting ticks of a `HZ` timer,
+//! so timestamps and durations can be shared in their native tick units.
+//!
+//! ```
+//! use fugit::{TimerDurationU32, TimerInstantU32};
+//! use relaxed::fugit::RelaxedInstant;
+//!
+//! static LAST_EDGE: RelaxedInstant<1_000> = RelaxedInstant::new(TimerInstantU32::from_ticks(0));
+//!
+//! // in the interrupt:
+//! LAST_EDGE.set(TimerInstantU32::from_ticks(250));
+//!
+//! // in a task:
+//! let now = TimerInstantU32::from_ticks(1_250);
+//! assert_eq!(LAST_EDGE.elapsed(now), Some(TimerDurationU32::millis(1_000)));
+//! ```
+
+use core::fmt::{Debug, Formatter};
+
+use ::fugit::{TimerDurationU32, TimerInstantU32};
+
+use crate::{RelaxedU32, RelaxedValue};
+
+macro_rules! fugit_impls {
+    ($name:ident: $inner:ident) => {
+        impl<const HZ: u32> $name<HZ> {
+            /// Create a new value.
+            #[inline(always)]
+            pub const fn new(val: $inner<HZ>) -> Self {
+                $name(RelaxedU32::new(val.ticks()))
+            }
+
+            /// Performs an atomic load with relaxed ordering.
+            #[inline(always)]
+            pub fn get(&self) -> $inner<HZ> {
+                $inner::from_ticks(self.0.get())
+            }
+            /// Performs an atomic store with relaxed ordering.
+            #[inline(always)]
+            pub fn set(&self, val: $inner<HZ>) {
+                self.0.set(val.ticks())
+            }
+            /// Stores a value, returning the previous value.
+            #[inline(always)]
+            pub fn swap(&self, val: $inner<HZ>) -> $inner<HZ> {
+                $inner::from_ticks(self.0.swap(val.ticks()))
+            }
+            /// Stores `new` if the current value is `current`.
+            /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+            #[inline(always)]
+            pub fn compare_exchange(
+                &self,
+                current: $inner<HZ>,
+                new: $inner<HZ>,
+            ) -> Result<$inner<HZ>, $inner<HZ>> {
+                self.0
+                    .compare_exchange(current.ticks(), new.ticks())
+                    .map($inner::from_ticks)
+                    .map_err($inner::from_ticks)
+            }
+        }
+
+        impl<const HZ: u32> RelaxedValue for $name<HZ> {
+            type Value = $inner<HZ>;
+
+            #[inline(always)]
+            fn get(&self) -> $inner<HZ> {
+                $name::get(self)
+            }
+            #[inline(always)]
+            fn set(&self, val: $inner<HZ>) {
+                $name::set(self, val)
+            }
+            #[inline(always)]
+            fn swap(&self, val: $inner<HZ>) -> $inner<HZ> {
+                $name::swap(self, val)
+            }
+            #[inline(always)]
+            fn compare_exchange(
+                &self,
+                current: $inner<HZ>,
+                new: $inner<HZ>,
+            ) -> Result<$inner<HZ>, $inner<HZ>> {
+                $name::compare_exchange(self, current, new)
+            }
+        }
+
+        impl<const HZ: u32> Default for $name<HZ> {
+            fn default() -> Self {
+                Self::new($inner::from_ticks(0))
+            }
+        }
+
+        impl<const HZ: u32> Debug for $name<HZ> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl<const HZ: u32> From<$inner<HZ>> for $name<HZ> {
+            fn from(val: $inner<HZ>) -> Self {
+                Self::new(val)
+            }
+        }
+    };
+}
+
+/// A [`TimerInstantU32`] that always uses `Ordering::Relaxed` for access.
+#[repr(transparent)]
+pub struct RelaxedInstant<const HZ: u32>(RelaxedU32);
+
+/// A [`TimerDurationU32`] that always uses `Ordering::Relaxed` for access.
+#[repr(transparent)]
+pub struct RelaxedDuration<const HZ: u32>(RelaxedU32);
+
+fugit_impls!(RelaxedInstant: TimerInstantU32);
+fugit_impls!(RelaxedDuration: TimerDurationU32);
+
+impl<const HZ: u32> RelaxedInstant<HZ> {
+    /// Returns the time passed from the stored instant until `now`,
+    /// or `None` if `now` is before it.
+    #[inline(always)]
+    pub fn elapsed(&self, now: TimerInstantU32<HZ>) -> Option<TimerDurationU32<HZ>> {
+        now.checked_duration_since(self.get())
+    }
+}
+
+impl<const HZ: u32> RelaxedDuration<HZ> {
+    /// Adds `val`, saturating at the maximum duration, returning the previous value.
+    #[inline(always)]
+    pub fn saturating_add(&self, val: TimerDurationU32<HZ>) -> TimerDurationU32<HZ> {
+        TimerDurationU32::from_ticks(
+            self.0
+                .get_and_update(|ticks| ticks.saturating_add(val.ticks())),
+        )
+    }
+}
@@ -0,0 +1,123 @@
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::Ordering;
+
+use atomic::Atomic;
+use bytemuck::NoUninit;
+
+use crate::RelaxedValue;
+
+/// A wrapper around an [`atomic::Atomic`], that always uses `Ordering::Relaxed` for access.
+///
+/// Works for any `Copy` type without uninitialized bytes, which includes fieldless enums and
+/// small structs deriving [`NoUninit`]. Types that don't fit a native atomic fall back to a lock,
+/// which [`is_lock_free`](Self::is_lock_free) reports.
+#[derive(Default)]
+#[repr(transparent)]
+pub struct Relaxed<T>(Atomic<T>);
+
+impl<T> Relaxed<T> {
+    /// Create a new value.
+    #[inline(always)]
+    pub const fn new(val: T) -> Self {
+        Relaxed(Atomic::new(val))
+    }
+
+    /// Returns the underlying atomic, e.g. to pass it to an API that requires one.
+    ///
+    /// Accesses through the atomic bypass the wrapper and may use any ordering.
+    #[inline(always)]
+    pub const fn as_atomic(&self) -> &Atomic<T> {
+        &self.0
+    }
+    /// Views an atomic as a relaxed wrapper, without copying it.
+    #[inline(always)]
+    pub const fn from_atomic(atomic: &Atomic<T>) -> &Self {
+        // SAFETY: `Self` is a `repr(transparent)` wrapper of the atomic
+        unsafe { &*(atomic as *const Atomic<T> as *const Self) }
+    }
+
+    /// Returns `true` if operations on values of this type are lock-free.
+    #[inline(always)]
+    pub const fn is_lock_free() -> bool {
+        Atomic::<T>::is_lock_free()
+    }
+}
+
+impl<T: NoUninit> Relaxed<T> {
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> T {
+        self.0.load(Ordering::Relaxed)
+    }
+    /// Performs an atomic store with relaxed ordering.
+    #[inline(always)]
+    pub fn set(&self, val: T) {
+        self.0.store(val, Ordering::Relaxed)
+    }
+    /// Calls `f` with the current value and stores the value returned by `f`.
+    /// **The update is not atomic.**
+    #[inline(always)]
+    pub fn update(&self, f: impl FnOnce(T) -> T) {
+        self.set(f(self.get()))
+    }
+    /// Stores a value, returning the previous value.
+    #[inline(always)]
+    pub fn swap(&self, val: T) -> T {
+        self.0.swap(val, Ordering::Relaxed)
+    }
+    /// Stores `new` if the current value has the same bytes as `current`.
+    /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+    #[inline(always)]
+    pub fn compare_exchange(&self, current: T, new: T) -> Result<T, T> {
+        self.0
+            .compare_exchange(current, new, Ordering::Relaxed, Ordering::Relaxed)
+    }
+    /// Fetches the value, and applies `f` to it that returns an optional new value.
+    /// Returns `Ok(previous_value)` if `f` returned `Some`, else `Err(previous_value)`.
+    ///
+    /// `f` may be called multiple times if the value was changed by other threads
+    /// in the meantime, but the new value is stored atomically.
+    #[inline(always)]
+    pub fn fetch_update(&self, f: impl FnMut(T) -> Option<T>) -> Result<T, T> {
+        self.0.fetch_update(Ordering::Relaxed, Ordering::Relaxed, f)
+    }
+}
+
+impl<T: NoUninit> RelaxedValue for Relaxed<T> {
+    type Value = T;
+
+    #[inline(always)]
+    fn get(&self) -> T {
+        Relaxed::get(self)
+    }
+    #[inline(always)]
+    fn set(&self, val: T) {
+        Relaxed::set(self, val)
+    }
+    #[inline(always)]
+    fn swap(&self, val: T) -> T {
+        Relaxed::swap(self, val)
+    }
+    #[inline(always)]
+    fn compare_exchange(&self, current: T, new: T) -> Result<T, T> {
+        Relaxed::compare_exchange(self, current, new)
+    }
+}
+
+impl<T: NoUninit + Debug> Debug for Relaxed<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl<T> From<T> for Relaxed<T> {
+    fn from(val: T) -> Self {
+        Relaxed::new(val)
+    }
+}
+
+impl<T> AsRef<Atomic<T>> for Relaxed<T> {
+    fn as_ref(&self) -> &Atomic<T> {
+        self.as_atomic()
+    }
+}
@@ -0,0 +1,16 @@
+use half::f16;
+
+use crate::RelaxedF16;
+
+impl RelaxedF16 {
+    /// Adds to the current value, returning the previous value.
+    #[inline(always)]
+    pub fn fetch_add(&self, val: f16) -> f16 {
+        self.get_and_update(|current| current + val)
+    }
+    /// Subtracts from the current value, returning the previous value.
+    #[inline(always)]
+    pub fn fetch_sub(&self, val: f16) -> f16 {
+        self.get_and_update(|current| current - val)
+    }
+}
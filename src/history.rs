@@ -0,0 +1,87 @@
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::RelaxedValue;
+
+/// A ring of the last `N` values recorded by a single writer, e.g. for a fault handler
+/// to dump recent sensor readings.
+///
+/// ```
+/// use relaxed::{RelaxedHistoryRing, RelaxedU16};
+///
+/// static RECENT: RelaxedHistoryRing<RelaxedU16, 4> =
+///     RelaxedHistoryRing::new([const { RelaxedU16::new(0) }; 4]);
+///
+/// for sample in 1..=6 {
+///     RECENT.record(sample);
+/// }
+/// assert_eq!(RECENT.snapshot(), [3, 4, 5, 6]);
+/// assert_eq!(RECENT.latest(), Some(6));
+/// assert_eq!(RECENT.recorded(), 6);
+/// ```
+pub struct RelaxedHistoryRing<R, const N: usize> {
+    slots: [R; N],
+    head: AtomicUsize,
+}
+
+impl<R: RelaxedValue, const N: usize> RelaxedHistoryRing<R, N> {
+    /// Create a new ring with the given slots, whose values show up in snapshots
+    /// until they are overwritten.
+    pub const fn new(slots: [R; N]) -> Self {
+        const { assert!(N > 0, "N must be non-zero") };
+        RelaxedHistoryRing {
+            slots,
+            head: AtomicUsize::new(0),
+        }
+    }
+
+    /// Records a value, overwriting the oldest one.
+    ///
+    /// Must only be called from a single context at a time.
+    pub fn record(&self, val: R::Value) {
+        let head = self.head.load(Ordering::Relaxed);
+        self.slots[head % N].set(val);
+        self.head.store(head.wrapping_add(1), Ordering::Relaxed);
+    }
+    /// Returns the number of values recorded so far, wrapping around on overflow.
+    #[inline(always)]
+    pub fn recorded(&self) -> usize {
+        self.head.load(Ordering::Relaxed)
+    }
+    /// Returns the most recently recorded value, or `None` if nothing was recorded yet.
+    pub fn latest(&self) -> Option<R::Value> {
+        let head = self.recorded();
+        (head != 0).then(|| self.slots[head.wrapping_sub(1) % N].get())
+    }
+    /// Loads the slots, oldest first.
+    ///
+    /// The slots are loaded one after another, a concurrent [`record`](Self::record)
+    /// may show up in place of the oldest values.
+    pub fn snapshot(&self) -> [R::Value; N] {
+        let head = self.recorded();
+        core::array::from_fn(|i| self.slots[head.wrapping_add(i) % N].get())
+    }
+}
+
+impl<R: RelaxedValue, const N: usize> Debug for RelaxedHistoryRing<R, N>
+where
+    R::Value: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.snapshot()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R: RelaxedValue, const N: usize> serde::Serialize for RelaxedHistoryRing<R, N>
+where
+    R::Value: serde::Serialize,
+{
+    /// Serializes a [`snapshot`](Self::snapshot) as a sequence, oldest first.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.snapshot())
+    }
+}
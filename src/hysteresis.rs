@@ -0,0 +1,87 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::{RelaxedBool, RelaxedI32};
+
+/// A boolean state driven by samples crossing a low and a high threshold.
+///
+/// The state turns on once a sample reaches the high threshold
+/// and only turns off again once a sample falls to the low threshold,
+/// so noisy samples between the two don't make it chatter.
+///
+/// ```
+/// use relaxed::RelaxedHysteresis;
+///
+/// static OVERHEAT: RelaxedHysteresis = RelaxedHysteresis::new(70, 80);
+///
+/// assert!(!OVERHEAT.feed(79));
+/// assert!(OVERHEAT.feed(80));
+/// assert!(OVERHEAT.feed(71));
+/// assert!(!OVERHEAT.feed(70));
+/// assert!(!OVERHEAT.state());
+/// ```
+pub struct RelaxedHysteresis {
+    low: RelaxedI32,
+    high: RelaxedI32,
+    state: RelaxedBool,
+}
+
+impl RelaxedHysteresis {
+    /// Create a new hysteresis in the off state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is above `high`.
+    #[inline(always)]
+    pub const fn new(low: i32, high: i32) -> Self {
+        assert!(low <= high, "low threshold above high threshold");
+        RelaxedHysteresis {
+            low: RelaxedI32::new(low),
+            high: RelaxedI32::new(high),
+            state: RelaxedBool::new(false),
+        }
+    }
+
+    /// Feeds a sample, returning the resulting state.
+    pub fn feed(&self, value: i32) -> bool {
+        if value >= self.high.get() {
+            self.state.set(true);
+            true
+        } else if value <= self.low.get() {
+            self.state.set(false);
+            false
+        } else {
+            self.state.get()
+        }
+    }
+    /// Returns the current state.
+    #[inline(always)]
+    pub fn state(&self) -> bool {
+        self.state.get()
+    }
+    /// Returns the `(low, high)` thresholds.
+    #[inline(always)]
+    pub fn thresholds(&self) -> (i32, i32) {
+        (self.low.get(), self.high.get())
+    }
+    /// Changes the thresholds, keeping the current state until the next sample.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is above `high`.
+    pub fn set_thresholds(&self, low: i32, high: i32) {
+        assert!(low <= high, "low threshold above high threshold");
+        self.low.set(low);
+        self.high.set(high);
+    }
+}
+
+impl Debug for RelaxedHysteresis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (low, high) = self.thresholds();
+        f.debug_struct("RelaxedHysteresis")
+            .field("low", &low)
+            .field("high", &high)
+            .field("state", &self.state())
+            .finish()
+    }
+}
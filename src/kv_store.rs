@@ -0,0 +1,130 @@
+use core::fmt::{Debug, Display, Formatter};
+
+use crate::RelaxedU32;
+
+/// Marks a slot whose key wasn't claimed yet.
+const FREE: u32 = u32::MAX;
+
+/// A fixed size map from `u16` keys to `u32` values, without allocation.
+///
+/// Keys claim the first free slot with a compare and exchange and are never removed,
+/// so every key occupies exactly one slot. Values are plain relaxed stores,
+/// a reader racing with the first [`set`](Self::set) of a key may see it with a value of zero.
+///
+/// ```
+/// use relaxed::RelaxedKvStore;
+///
+/// static PARAMS: RelaxedKvStore<8> = RelaxedKvStore::new();
+///
+/// PARAMS.set(0x10, 42).unwrap();
+/// PARAMS.set(0x20, 7).unwrap();
+/// PARAMS.set(0x10, 43).unwrap();
+///
+/// assert_eq!(PARAMS.get(0x10), Some(43));
+/// assert_eq!(PARAMS.get(0x30), None);
+/// assert_eq!(PARAMS.iter().collect::<Vec<_>>(), [(0x10, 43), (0x20, 7)]);
+/// ```
+pub struct RelaxedKvStore<const N: usize> {
+    keys: [RelaxedU32; N],
+    values: [RelaxedU32; N],
+}
+
+/// Error returned when every slot of a [`RelaxedKvStore`] is claimed by another key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StoreFull;
+
+impl Display for StoreFull {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("key-value store is full")
+    }
+}
+
+impl<const N: usize> RelaxedKvStore<N> {
+    /// Create a new empty store.
+    pub const fn new() -> Self {
+        RelaxedKvStore {
+            keys: [const { RelaxedU32::new(FREE) }; N],
+            values: [const { RelaxedU32::new(0) }; N],
+        }
+    }
+
+    /// Returns the slot of `key`, if it was claimed.
+    fn find(&self, key: u16) -> Option<usize> {
+        self.keys
+            .iter()
+            .map(RelaxedU32::get)
+            .take_while(|&k| k != FREE)
+            .position(|k| k == key as u32)
+    }
+    /// Returns the slot of `key`, claiming a free one if necessary.
+    fn find_or_claim(&self, key: u16) -> Result<usize, StoreFull> {
+        // slots are claimed in order, so two threads claiming the same key
+        // race for the same free slot and the loser finds the key there
+        for (i, slot) in self.keys.iter().enumerate() {
+            match slot.compare_exchange(FREE, key as u32) {
+                Ok(_) => return Ok(i),
+                Err(k) if k == key as u32 => return Ok(i),
+                Err(_) => {}
+            }
+        }
+        Err(StoreFull)
+    }
+
+    /// Returns the value of `key`, or `None` if it was never set.
+    pub fn get(&self, key: u16) -> Option<u32> {
+        self.find(key).map(|i| self.values[i].get())
+    }
+    /// Stores the value of `key`.
+    ///
+    /// # Errors
+    /// Fails if `key` wasn't set before and every slot is claimed.
+    pub fn set(&self, key: u16, val: u32) -> Result<(), StoreFull> {
+        let i = self.find_or_claim(key)?;
+        self.values[i].set(val);
+        Ok(())
+    }
+    /// Returns whether `key` was ever set.
+    pub fn contains_key(&self, key: u16) -> bool {
+        self.find(key).is_some()
+    }
+    /// Returns the number of claimed keys.
+    pub fn len(&self) -> usize {
+        self.keys.iter().take_while(|k| k.get() != FREE).count()
+    }
+    /// Returns `true` if no key was set yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns an iterator over the keys and values, in the order the keys were claimed.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u32)> + '_ {
+        self.keys
+            .iter()
+            .zip(&self.values)
+            .map(|(key, val)| (key.get(), val))
+            .take_while(|&(key, _)| key != FREE)
+            .map(|(key, val)| (key as u16, val.get()))
+    }
+}
+
+impl<const N: usize> Default for RelaxedKvStore<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Debug for RelaxedKvStore<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for RelaxedKvStore<N> {
+    /// Serializes the store as a map, in the order the keys were claimed.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
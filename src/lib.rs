@@ -13,19 +13,300 @@
 //! atomic.set(true);
 //! assert_eq!(atomic.get(), true);
 //! ```
+//!
+//! # Platform support
+//!
+//! The wrappers need compare and exchange for their read-modify-write operations.
+//! On `wasm32-unknown-unknown` core atomics are available with and without the
+//! `atomics` target feature: without it, the target is single-threaded
+//! and they compile to plain loads and stores, so no fallback is needed.
 
 #![no_std]
 #![warn(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::fmt::{Debug, Formatter, Display};
+#[cfg(not(relaxed_shuttle))]
 use core::sync::atomic::{
     AtomicBool, AtomicI16, AtomicI32, AtomicI8, AtomicU16, AtomicU32, AtomicU8, Ordering,
 };
+// `RUSTFLAGS="--cfg relaxed_shuttle"` swaps in shuttle's atomics so the tests in
+// `tests/shuttle.rs` can explore thread interleavings.
+#[cfg(relaxed_shuttle)]
+use shuttle::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI8, AtomicU16, AtomicU32, AtomicU8, Ordering,
+};
 
+#[cfg(feature = "atomic_float")]
 use atomic_float::AtomicF32;
+#[cfg(feature = "fixed")]
+use fixed::types::{I16F16, U16F16};
+#[cfg(feature = "half")]
+use half::f16;
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicI128, AtomicU128};
+#[cfg(all(target_has_atomic = "64", not(relaxed_shuttle)))]
+use core::sync::atomic::AtomicU64;
+#[cfg(all(not(target_has_atomic = "64"), feature = "portable-atomic"))]
+use portable_atomic::AtomicU64;
+#[cfg(relaxed_shuttle)]
+use shuttle::sync::atomic::AtomicU64;
+
+mod array;
+mod backoff;
+mod barrier;
+mod bloom;
+mod bytes;
+mod calibrated;
+mod call_site;
+mod checked;
+mod config_bank;
+mod counter;
+mod counter_bank;
+mod deadline;
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+mod duration;
+mod event_log;
+mod fn_ptr;
+mod history;
+mod hysteresis;
+mod kv_store;
+mod macros;
+mod min_max;
+mod monotonic;
+mod option;
+mod peak;
+mod persistent;
+mod ptr;
+mod reg;
+mod rgb;
+mod ring_buffer;
+mod scaled;
+mod seqlock;
+mod slew;
+mod slice;
+mod soft_timers;
+mod status;
+mod str_ref;
+mod string;
+mod tagged_ptr;
+mod tmr;
+mod vector;
+mod version;
+mod waker;
+mod watched;
+mod xorshift;
+
+#[cfg(feature = "alloc")]
+mod arc;
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+mod batched;
+#[cfg(feature = "alloc")]
+mod bit_vec;
+#[cfg(feature = "alloc")]
+mod option_box;
+#[cfg(all(feature = "bitband", target_arch = "arm", target_os = "none"))]
+pub mod bitband;
+#[cfg(feature = "embassy")]
+pub mod embassy;
+#[cfg(feature = "fixed")]
+mod fixed_point;
+#[cfg(feature = "fugit")]
+pub mod fugit;
+#[cfg(feature = "atomic")]
+mod generic;
+#[cfg(feature = "half")]
+mod half_float;
+#[cfg(feature = "log")]
+mod log_level;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "embedded-hal")]
+mod mock_pin;
+#[cfg(feature = "std")]
+pub mod prometheus;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "serde")]
+pub mod serde_bits;
+#[cfg(feature = "alloc")]
+mod shared;
+#[cfg(all(feature = "signal", unix))]
+mod signal;
+#[cfg(feature = "trace")]
+pub mod trace;
+#[cfg(feature = "critical-section")]
+mod transaction;
+#[cfg(feature = "uom")]
+pub mod uom;
+
+pub use array::RelaxedArray;
+pub use backoff::{Backoff, BackoffStrategy, NoBackoff};
+pub use barrier::StartBarrier;
+pub use bloom::RelaxedBloom;
+pub use bytes::{
+    copy_from_relaxed, copy_from_relaxed_words, copy_to_relaxed, copy_to_relaxed_words,
+};
+pub use calibrated::RelaxedCalibrated;
+pub use call_site::{call_sites, CallSite, CallSites};
+pub use checked::{CorruptionError, RelaxedCheckedU16};
+pub use config_bank::{BankKey, ConfigBank};
+pub use counter::{
+    Checked, OverflowPolicy, RelaxedCheckedCounter, RelaxedCounter, RelaxedDeltaCounter, Saturating,
+    Wrapping,
+};
+pub use counter_bank::CounterBank;
+pub use deadline::RelaxedDeadline;
+pub use event_log::RelaxedEventLog;
+pub use fn_ptr::{RelaxedFn0, RelaxedFn1, RelaxedFn2};
+pub use history::RelaxedHistoryRing;
+pub use hysteresis::RelaxedHysteresis;
+pub use kv_store::{RelaxedKvStore, StoreFull};
+pub use min_max::RelaxedMinMaxU16;
+pub use monotonic::RelaxedMonotonicU32;
+pub use option::{RelaxedOption, RelaxedOptionF32, Sentinel};
+pub use peak::{RelaxedPeakF32, RelaxedPeakU16};
+pub use persistent::PersistentCell;
+pub use ptr::RelaxedPtr;
+pub use reg::{RegValue, RelaxedReg};
+pub use ring_buffer::{Consumer, Producer, RelaxedRingBuffer};
+pub use scaled::RelaxedScaled;
+pub use slew::RelaxedSlewF32;
+pub use slice::RelaxedSliceExt;
+pub use soft_timers::SoftTimers;
+pub use status::RelaxedStatus;
+pub use str_ref::RelaxedStrRef;
+pub use string::RelaxedString;
+pub use tagged_ptr::RelaxedTaggedPtr;
+pub use tmr::{RelaxedTmr, Vote};
+pub use vector::{RelaxedVec2F32, RelaxedVec2I16, RelaxedVec3F32, RelaxedVec3I16};
+pub use waker::WakerSlot;
+pub use watched::Watched;
+pub use xorshift::RelaxedXorShift32;
+#[cfg(feature = "atomic")]
+pub use generic::Relaxed;
+#[cfg(feature = "log")]
+pub use log_level::RelaxedLogLevel;
+#[cfg(feature = "embedded-hal")]
+pub use mock_pin::MockPin;
+#[cfg(feature = "alloc")]
+pub use arc::RelaxedArc;
+#[cfg(feature = "alloc")]
+pub use bit_vec::{IterOnes, RelaxedBitVec};
+#[cfg(feature = "alloc")]
+pub use option_box::RelaxedOptionBox;
+#[cfg(feature = "alloc")]
+pub use shared::{shared, ReaderHandle, WriterHandle};
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+pub use batched::BatchedCounter;
+#[cfg(feature = "critical-section")]
+pub use transaction::{transaction, Transaction, TransactionExt};
+/// Generates a `Relaxed<Name>` wrapper for a newtype over a primitive,
+/// whose accessors take and return the newtype.
+///
+/// ```
+/// use relaxed::{relaxed_static, RelaxedOps};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, RelaxedOps)]
+/// pub struct Rpm(u32);
+///
+/// static SPEED: RelaxedRpm = RelaxedRpm::new(Rpm(0));
+///
+/// SPEED.set(Rpm(1200));
+/// assert_eq!(SPEED.update_and_get(|Rpm(rpm)| Rpm(rpm + 100)), Rpm(1300));
+///
+/// relaxed_static! {
+///     static IDLE: Rpm = Rpm(800);
+/// }
+/// assert_eq!(IDLE.get(), Rpm(800));
+/// ```
+///
+/// The generated code uses absolute paths, so it works next to shadowing aliases:
+///
+/// ```
+/// use relaxed::RelaxedOps;
+///
+/// #[allow(dead_code)]
+/// type Result<T> = core::result::Result<T, ()>;
+/// #[allow(dead_code)]
+/// type Option = ();
+///
+/// #[derive(Clone, Copy, RelaxedOps)]
+/// pub struct Celsius(i16);
+///
+/// static ROOM: RelaxedCelsius = RelaxedCelsius::new(Celsius(20));
+/// assert!(ROOM.compare_exchange(Celsius(20), Celsius(21)).is_ok());
+/// ```
+#[cfg(feature = "derive")]
+pub use relaxed_derive::RelaxedOps;
+
+/// Common interface of all relaxed wrappers, for code that is generic over the wrapped type.
+pub trait RelaxedValue {
+    /// The wrapped value type.
+    type Value: Copy;
+
+    /// Performs an atomic load with relaxed ordering.
+    fn get(&self) -> Self::Value;
+    /// Performs an atomic store with relaxed ordering.
+    fn set(&self, val: Self::Value);
+    /// Stores a value, returning the previous value.
+    fn swap(&self, val: Self::Value) -> Self::Value;
+    /// Stores `new` if the current value is `current`.
+    /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+    fn compare_exchange(&self, current: Self::Value, new: Self::Value)
+        -> Result<Self::Value, Self::Value>;
+}
+
+/// Value types that have a relaxed wrapper, e.g. `u32` is wrapped by [`RelaxedU32`].
+pub trait Relaxable: Copy {
+    /// The relaxed wrapper of this type.
+    type Relaxed: RelaxedValue<Value = Self>;
+}
+
+/// Error returned by bounded retrying updates, like [`RelaxedU32::fetch_update_bounded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateError<T> {
+    /// The closure returned `None` for this value.
+    Rejected(T),
+    /// Every attempt failed because the value was changed by other threads.
+    /// Contains the latest value.
+    Exhausted(T),
+}
+
+impl<T> UpdateError<T> {
+    /// Returns the value contained in either variant.
+    pub fn into_inner(self) -> T {
+        match self {
+            UpdateError::Rejected(val) | UpdateError::Exhausted(val) => val,
+        }
+    }
+}
+
+/// Restores a previous value when dropped.
+struct Restore<'a, R: RelaxedValue> {
+    cell: &'a R,
+    previous: R::Value,
+}
+
+impl<R: RelaxedValue> Drop for Restore<'_, R> {
+    fn drop(&mut self) {
+        self.cell.set(self.previous)
+    }
+}
+
+#[inline(always)]
+const fn identity<T>(val: T) -> T {
+    val
+}
 
 macro_rules! impls {
-    ($name:ident: $atomic:ident, $inner:ty) => {
+    (@base $name:ident: $atomic:ident, $inner:ty, $to:path, $from:path) => {
         /// A Wrapper around a atomic value, that always uses `Ordering::Relaxed` for access.
         #[derive(Default)]
         #[repr(transparent)]
@@ -35,18 +316,64 @@ macro_rules! impls {
             /// Create a new value.
             #[inline(always)]
             pub const fn new(val: $inner) -> Self {
-                $name($atomic::new(val))
+                $name($atomic::new($to(val)))
+            }
+            /// Create a new value behind an `Arc`, returning a reader and a writer handle to it.
+            #[cfg(feature = "alloc")]
+            pub fn shared(val: $inner) -> (ReaderHandle<Self>, WriterHandle<Self>) {
+                shared(Self::new(val))
             }
 
             /// Performs an atomic load with relaxed ordering.
             #[inline(always)]
             pub fn get(&self) -> $inner {
-                self.0.load(Ordering::Relaxed)
+                $from(self.0.load(Ordering::Relaxed))
             }
             /// Performs an atomic store with relaxed ordering.
             #[inline(always)]
             pub fn set(&self, val: $inner) {
-                self.0.store(val, Ordering::Relaxed)
+                #[cfg(feature = "trace")]
+                {
+                    let new = $to(val);
+                    self.trace(self.0.swap(new, Ordering::Relaxed), new);
+                }
+                #[cfg(not(feature = "trace"))]
+                self.0.store($to(val), Ordering::Relaxed)
+            }
+            /// Alias of [`get`](Self::get), for code migrating from the core atomics.
+            #[cfg(feature = "compat")]
+            #[inline(always)]
+            pub fn load(&self) -> $inner {
+                self.get()
+            }
+            /// Alias of [`set`](Self::set), for code migrating from the core atomics.
+            #[cfg(feature = "compat")]
+            #[inline(always)]
+            pub fn store(&self, val: $inner) {
+                self.set(val)
+            }
+            /// Stores a value, returning the previous value.
+            #[inline(always)]
+            pub fn swap(&self, val: $inner) -> $inner {
+                let new = $to(val);
+                let previous = self.0.swap(new, Ordering::Relaxed);
+                #[cfg(feature = "trace")]
+                self.trace(previous, new);
+                $from(previous)
+            }
+            /// Stores `new` if the current value is `current`.
+            /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+            #[inline(always)]
+            pub fn compare_exchange(&self, current: $inner, new: $inner) -> Result<$inner, $inner> {
+                let new = $to(new);
+                let result =
+                    self.0
+                        .compare_exchange($to(current), new, Ordering::Relaxed, Ordering::Relaxed);
+                #[cfg(feature = "trace")]
+                if let Ok(previous) = result {
+                    self.trace(previous, new);
+                }
+                result.map($from).map_err($from)
             }
             /// Calls `f` with the current value and stores the value returned by `f`.
             /// **The update is not atomic.**
@@ -54,17 +381,249 @@ macro_rules! impls {
             pub fn update(&self, f: impl FnOnce($inner) -> $inner) {
                 self.set(f(self.get()))
             }
+            /// Fetches the value, and applies `f` to it that returns an optional new value.
+            /// Returns `Ok(previous_value)` if `f` returned `Some`, else `Err(previous_value)`.
+            ///
+            /// `f` may be called multiple times if the value was changed by other threads
+            /// in the meantime, but the new value is stored atomically.
+            #[inline(always)]
+            pub fn fetch_update(
+                &self,
+                mut f: impl FnMut($inner) -> Option<$inner>,
+            ) -> Result<$inner, $inner> {
+                let mut backoff = Backoff::new();
+                let mut current = self.0.load(Ordering::Relaxed);
+                while let Some(new) = f($from(current)) {
+                    let new = $to(new);
+                    match self.0.compare_exchange_weak(
+                        current,
+                        new,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(previous) => {
+                            #[cfg(feature = "trace")]
+                            self.trace(previous, new);
+                            return Ok($from(previous));
+                        }
+                        Err(actual) => {
+                            backoff.spin();
+                            current = actual;
+                        }
+                    }
+                }
+                Err($from(current))
+            }
+            /// Like [`fetch_update`](Self::fetch_update), but gives up after `max_retries` failed
+            /// retries instead of looping until it succeeds.
+            #[inline(always)]
+            pub fn fetch_update_bounded(
+                &self,
+                max_retries: u32,
+                f: impl FnMut($inner) -> Option<$inner>,
+            ) -> Result<$inner, UpdateError<$inner>> {
+                self.fetch_update_with(max_retries, NoBackoff, f)
+            }
+            /// Like [`fetch_update_bounded`](Self::fetch_update_bounded), but calls `backoff`
+            /// before every retry.
+            pub fn fetch_update_with(
+                &self,
+                max_retries: u32,
+                mut backoff: impl BackoffStrategy,
+                mut f: impl FnMut($inner) -> Option<$inner>,
+            ) -> Result<$inner, UpdateError<$inner>> {
+                let mut current = self.get();
+                let mut retry = 0;
+                loop {
+                    let Some(new) = f(current) else {
+                        return Err(UpdateError::Rejected(current));
+                    };
+                    let new = $to(new);
+                    match self.0.compare_exchange(
+                        $to(current),
+                        new,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(previous) => {
+                            #[cfg(feature = "trace")]
+                            self.trace(previous, new);
+                            return Ok($from(previous));
+                        }
+                        Err(actual) if retry == max_retries => {
+                            return Err(UpdateError::Exhausted($from(actual)))
+                        }
+                        Err(actual) => {
+                            retry += 1;
+                            backoff.backoff(retry);
+                            current = $from(actual);
+                        }
+                    }
+                }
+            }
+            /// Stores `new` if `pred` returns `true` for the current value.
+            /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+            ///
+            /// `pred` may be called multiple times if the value was changed by other threads
+            /// in the meantime.
+            #[inline(always)]
+            pub fn update_if(
+                &self,
+                mut pred: impl FnMut($inner) -> bool,
+                new: $inner,
+            ) -> Result<$inner, $inner> {
+                self.fetch_update(|current| pred(current).then_some(new))
+            }
+            /// Atomically replaces the value with the result of `f` and returns the previous value.
+            ///
+            /// `f` may be called multiple times if the value was changed by other threads
+            /// in the meantime.
+            #[inline(always)]
+            pub fn get_and_update(&self, mut f: impl FnMut($inner) -> $inner) -> $inner {
+                match self.fetch_update(|val| Some(f(val))) {
+                    Ok(previous) | Err(previous) => previous,
+                }
+            }
+            /// Atomically replaces the value with the result of `f` and returns the new value.
+            ///
+            /// `f` may be called multiple times if the value was changed by other threads
+            /// in the meantime.
+            #[inline(always)]
+            pub fn update_and_get(&self, mut f: impl FnMut($inner) -> $inner) -> $inner {
+                let mut new = Default::default();
+                self.get_and_update(|val| {
+                    new = f(val);
+                    new
+                });
+                new
+            }
+            /// Applies `f` to the current value and attempts to store the result exactly once.
+            /// Returns `Ok(previous_value)` on success, or `Err(current_value)` if the value was
+            /// changed by another thread in the meantime.
+            #[inline(always)]
+            pub fn try_update(&self, f: impl FnOnce($inner) -> $inner) -> Result<$inner, $inner> {
+                let current = self.get();
+                self.compare_exchange(current, f(current))
+            }
+            /// Loads the value and converts it with [`TryFrom`],
+            /// e.g. into an enum encoded by the value.
+            #[inline(always)]
+            pub fn get_as<T: TryFrom<$inner>>(&self) -> Result<T, T::Error> {
+                T::try_from(self.get())
+            }
+            /// Converts `val` with [`Into`] and stores it.
+            #[inline(always)]
+            pub fn set_from_into<T: Into<$inner>>(&self, val: T) {
+                self.set(val.into())
+            }
+            /// Stores the value of `other`.
+            #[inline(always)]
+            pub fn set_from(&self, other: &Self) {
+                self.set(other.get())
+            }
+            /// Stores the value of `other` converted with [`Into`].
+            #[inline(always)]
+            pub fn set_from_relaxed<O>(&self, other: &O)
+            where
+                O: RelaxedValue,
+                O::Value: Into<$inner>,
+            {
+                self.set(other.get().into())
+            }
+            /// Stores the value of `other` converted with [`TryFrom`].
+            /// Leaves the current value untouched if the conversion fails.
+            #[inline(always)]
+            pub fn try_set_from_relaxed<O>(
+                &self,
+                other: &O,
+            ) -> Result<(), <$inner as TryFrom<O::Value>>::Error>
+            where
+                O: RelaxedValue,
+                $inner: TryFrom<O::Value>,
+            {
+                self.set(<$inner>::try_from(other.get())?);
+                Ok(())
+            }
+            /// Stores `val`, calls `f` and restores the previous value afterwards,
+            /// even if `f` panics or returns early.
+            #[inline(always)]
+            pub fn with_value<R>(&self, val: $inner, f: impl FnOnce() -> R) -> R {
+                let _restore = Restore {
+                    cell: self,
+                    previous: self.swap(val),
+                };
+                f()
+            }
+            /// Busy-waits as long as `pred` returns `true` for the current value,
+            /// e.g. until an interrupt handler changed it. Returns the value that ended the wait.
+            #[inline]
+            pub fn spin_while(&self, mut pred: impl FnMut($inner) -> bool) -> $inner {
+                loop {
+                    let current = self.get();
+                    if !pred(current) {
+                        return current;
+                    }
+                    core::hint::spin_loop();
+                }
+            }
+            /// Like [`spin_while`](Self::spin_while), but gives up after `max_spins` iterations.
+            /// Returns `Ok` with the value that ended the wait, or `Err` with the last value seen.
+            #[inline]
+            pub fn spin_while_bounded(
+                &self,
+                max_spins: u32,
+                mut pred: impl FnMut($inner) -> bool,
+            ) -> Result<$inner, $inner> {
+                let mut current = self.get();
+                for _ in 0..max_spins {
+                    if !pred(current) {
+                        return Ok(current);
+                    }
+                    core::hint::spin_loop();
+                    current = self.get();
+                }
+                if pred(current) {
+                    Err(current)
+                } else {
+                    Ok(current)
+                }
+            }
+            /// Reports a store of the raw values `old` and `new` to the trace hook.
+            #[cfg(feature = "trace")]
+            #[inline(always)]
+            pub(crate) fn trace<B: trace::Bits>(&self, old: B, new: B) {
+                trace::emit(stringify!($name), (self as *const Self).cast(), old, new)
+            }
         }
 
-        impl Debug for $name {
-            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-                self.0.fmt(f)
+        impl RelaxedValue for $name {
+            type Value = $inner;
+
+            #[inline(always)]
+            fn get(&self) -> $inner {
+                $name::get(self)
+            }
+            #[inline(always)]
+            fn set(&self, val: $inner) {
+                $name::set(self, val)
+            }
+            #[inline(always)]
+            fn swap(&self, val: $inner) -> $inner {
+                $name::swap(self, val)
+            }
+            #[inline(always)]
+            fn compare_exchange(&self, current: $inner, new: $inner) -> Result<$inner, $inner> {
+                $name::compare_exchange(self, current, new)
             }
         }
 
-        impl Display for $name {
+        impl Relaxable for $inner {
+            type Relaxed = $name;
+        }
+
+        impl Debug for $name {
             fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-                self.0.fmt(f)
+                Debug::fmt(&self.get(), f)
             }
         }
 
@@ -73,7 +632,15 @@ macro_rules! impls {
                 $name::new(val)
             }
         }
-
+    };
+    (@display $name:ident) => {
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Display::fmt(&self.get(), f)
+            }
+        }
+    };
+    (@serde $name:ident) => {
         #[cfg(feature="serde")]
         impl serde::Serialize for $name {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -81,7 +648,7 @@ macro_rules! impls {
                 S: serde::Serializer,
             {
                 // Matches the atomic ordering used in libcore for the Debug impl
-                self.0.load(Ordering::Relaxed).serialize(serializer)
+                self.get().serialize(serializer)
             }
         }
 
@@ -94,25 +661,654 @@ macro_rules! impls {
                 serde::Deserialize::deserialize(deserializer).map(Self::new)
             }
         }
+
+        #[cfg(feature="postcard")]
+        impl $name {
+            /// Encodes the current value with postcard into `buf`,
+            /// returning the used part of the buffer.
+            pub fn encode_into<'b>(&self, buf: &'b mut [u8]) -> postcard::Result<&'b mut [u8]> {
+                postcard::to_slice(self, buf)
+            }
+        }
+    };
+    (@schemars $name:ident) => {
+        #[cfg(feature = "schemars")]
+        impl schemars::JsonSchema for $name {
+            fn inline_schema() -> bool {
+                <<$name as RelaxedValue>::Value as schemars::JsonSchema>::inline_schema()
+            }
+            fn schema_name() -> alloc::borrow::Cow<'static, str> {
+                <<$name as RelaxedValue>::Value as schemars::JsonSchema>::schema_name()
+            }
+            fn schema_id() -> alloc::borrow::Cow<'static, str> {
+                <<$name as RelaxedValue>::Value as schemars::JsonSchema>::schema_id()
+            }
+            fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                <<$name as RelaxedValue>::Value as schemars::JsonSchema>::json_schema(generator)
+            }
+        }
+    };
+    (@bincode $name:ident) => {
+        impls!(@bincode $name, identity, identity);
+    };
+    (@bincode $name:ident, $to:path, $from:path) => {
+        #[cfg(feature="bincode")]
+        impl bincode::Encode for $name {
+            fn encode<E: bincode::enc::Encoder>(
+                &self,
+                encoder: &mut E,
+            ) -> Result<(), bincode::error::EncodeError> {
+                bincode::Encode::encode(&$to(self.get()), encoder)
+            }
+        }
+
+        #[cfg(feature="bincode")]
+        impl<Context> bincode::Decode<Context> for $name {
+            fn decode<D: bincode::de::Decoder<Context = Context>>(
+                decoder: &mut D,
+            ) -> Result<Self, bincode::error::DecodeError> {
+                bincode::Decode::decode(decoder).map($from).map(Self::new)
+            }
+        }
+
+        #[cfg(feature="bincode")]
+        bincode::impl_borrow_decode!($name);
+    };
+    (@borsh $name:ident) => {
+        impls!(@borsh $name, identity, identity);
+    };
+    (@borsh $name:ident, $to:path, $from:path) => {
+        #[cfg(feature="borsh")]
+        impl borsh::BorshSerialize for $name {
+            fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+                borsh::BorshSerialize::serialize(&$to(self.get()), writer)
+            }
+        }
+
+        #[cfg(feature="borsh")]
+        impl borsh::BorshDeserialize for $name {
+            fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+                borsh::BorshDeserialize::deserialize_reader(reader).map($from).map(Self::new)
+            }
+        }
+    };
+    (@from_str $name:ident) => {
+        impl core::str::FromStr for $name {
+            type Err = <<$name as RelaxedValue>::Value as core::str::FromStr>::Err;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(Self::new)
+            }
+        }
+
+        impl $name {
+            /// Parses `s` as the inner type and stores the result.
+            /// The value is left unchanged if parsing fails.
+            #[inline(always)]
+            pub fn set_from_str(&self, s: &str) -> Result<(), <Self as core::str::FromStr>::Err> {
+                s.parse().map(|val| self.set(val))
+            }
+        }
+    };
+    (@minicbor $name:ident) => {
+        impls!(@minicbor $name, identity, identity);
+    };
+    (@minicbor $name:ident, $to:path, $from:path) => {
+        #[cfg(feature="minicbor")]
+        impl<C> minicbor::Encode<C> for $name {
+            fn encode<W: minicbor::encode::Write>(
+                &self,
+                e: &mut minicbor::Encoder<W>,
+                ctx: &mut C,
+            ) -> Result<(), minicbor::encode::Error<W::Error>> {
+                minicbor::Encode::encode(&$to(self.get()), e, ctx)
+            }
+        }
+
+        #[cfg(feature="minicbor")]
+        impl<'b, C> minicbor::Decode<'b, C> for $name {
+            fn decode(
+                d: &mut minicbor::Decoder<'b>,
+                ctx: &mut C,
+            ) -> Result<Self, minicbor::decode::Error> {
+                minicbor::Decode::decode(d, ctx).map($from).map(Self::new)
+            }
+        }
+    };
+    (@atomic $name:ident: $atomic:ident) => {
+        impl $name {
+            /// Returns the underlying atomic, e.g. to pass it to an API that requires one.
+            ///
+            /// Accesses through the atomic bypass the wrapper and may use any ordering.
+            #[inline(always)]
+            pub const fn as_atomic(&self) -> &$atomic {
+                &self.0
+            }
+            /// Views an atomic as a relaxed wrapper, without copying it.
+            #[inline(always)]
+            pub const fn from_atomic(atomic: &$atomic) -> &Self {
+                // SAFETY: `Self` is a `repr(transparent)` wrapper of the atomic
+                unsafe { &*(atomic as *const $atomic as *const Self) }
+            }
+        }
+
+        impl AsRef<$atomic> for $name {
+            fn as_ref(&self) -> &$atomic {
+                self.as_atomic()
+            }
+        }
+    };
+    ($name:ident: $atomic:ident, $inner:ty, $to:path, $from:path) => {
+        impls!(@base $name: $atomic, $inner, $to, $from);
+    };
+    ($name:ident: $atomic:ident, $inner:ty) => {
+        impls!(@base $name: $atomic, $inner, identity, identity);
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impls!(@serde $name);
+        impls!(@schemars $name);
+        impls!(@minicbor $name);
+        impls!(@bincode $name);
+        impls!(@borsh $name);
+        impls!(@from_str $name);
     };
 }
 
 impls!(RelaxedBool: AtomicBool, bool);
+impls!(@atomic RelaxedBool: AtomicBool);
 
 impls!(RelaxedU8: AtomicU8, u8);
+impls!(@atomic RelaxedU8: AtomicU8);
 impls!(RelaxedU16: AtomicU16, u16);
+impls!(@atomic RelaxedU16: AtomicU16);
 impls!(RelaxedU32: AtomicU32, u32);
+impls!(@atomic RelaxedU32: AtomicU32);
 
 impls!(RelaxedI8: AtomicI8, i8);
+impls!(@atomic RelaxedI8: AtomicI8);
 impls!(RelaxedI16: AtomicI16, i16);
+impls!(@atomic RelaxedI16: AtomicI16);
 impls!(RelaxedI32: AtomicI32, i32);
+impls!(@atomic RelaxedI32: AtomicI32);
 
+// `RelaxedF32` doesn't expose its atomic, whose type depends on the `atomic_float` feature.
+#[cfg(feature = "atomic_float")]
 impls!(RelaxedF32: AtomicF32, f32);
 
+// Without `atomic_float`, the bit pattern is stored in an `AtomicU32`
+// and read-modify-write operations use compare and exchange loops.
+#[cfg(not(feature = "atomic_float"))]
+impls!(RelaxedF32: AtomicU32, f32, f32::to_bits, f32::from_bits);
+#[cfg(not(feature = "atomic_float"))]
+impls!(@display RelaxedF32);
+#[cfg(not(feature = "atomic_float"))]
+impls!(@serde RelaxedF32);
+#[cfg(not(feature = "atomic_float"))]
+impls!(@schemars RelaxedF32);
+#[cfg(not(feature = "atomic_float"))]
+impls!(@minicbor RelaxedF32);
+#[cfg(not(feature = "atomic_float"))]
+impls!(@bincode RelaxedF32);
+#[cfg(not(feature = "atomic_float"))]
+impls!(@borsh RelaxedF32);
+#[cfg(not(feature = "atomic_float"))]
+impls!(@from_str RelaxedF32);
+
+impls!(RelaxedRgb: AtomicU32, (u8, u8, u8), rgb::pack, rgb::unpack);
+impls!(@atomic RelaxedRgb: AtomicU32);
+impls!(@serde RelaxedRgb);
+impls!(@schemars RelaxedRgb);
+impls!(@minicbor RelaxedRgb);
+impls!(@bincode RelaxedRgb);
+impls!(@borsh RelaxedRgb);
+
+impls!(RelaxedVersion: AtomicU32, (u8, u8, u16), version::pack, version::unpack);
+impls!(@atomic RelaxedVersion: AtomicU32);
+impls!(@serde RelaxedVersion);
+impls!(@schemars RelaxedVersion);
+impls!(@minicbor RelaxedVersion);
+impls!(@bincode RelaxedVersion);
+impls!(@borsh RelaxedVersion);
+
+#[cfg(feature = "portable-atomic")]
+impls!(RelaxedU128: AtomicU128, u128);
+#[cfg(feature = "portable-atomic")]
+impls!(@atomic RelaxedU128: AtomicU128);
+#[cfg(feature = "portable-atomic")]
+impls!(RelaxedI128: AtomicI128, i128);
+#[cfg(feature = "portable-atomic")]
+impls!(@atomic RelaxedI128: AtomicI128);
+
+#[cfg(feature = "fixed")]
+impls!(RelaxedI16F16: AtomicI32, I16F16, I16F16::to_bits, I16F16::from_bits);
+#[cfg(feature = "fixed")]
+impls!(@atomic RelaxedI16F16: AtomicI32);
+#[cfg(feature = "fixed")]
+impls!(@display RelaxedI16F16);
+#[cfg(feature = "fixed")]
+impls!(@serde RelaxedI16F16);
+#[cfg(feature = "fixed")]
+impls!(@from_str RelaxedI16F16);
+// encoded as the raw bits, none of the formats has a fixed point type
+#[cfg(feature = "fixed")]
+impls!(@minicbor RelaxedI16F16, I16F16::to_bits, I16F16::from_bits);
+#[cfg(feature = "fixed")]
+impls!(@bincode RelaxedI16F16, I16F16::to_bits, I16F16::from_bits);
+#[cfg(feature = "fixed")]
+impls!(@borsh RelaxedI16F16, I16F16::to_bits, I16F16::from_bits);
+
+#[cfg(feature = "fixed")]
+impls!(RelaxedU16F16: AtomicU32, U16F16, U16F16::to_bits, U16F16::from_bits);
+#[cfg(feature = "fixed")]
+impls!(@atomic RelaxedU16F16: AtomicU32);
+#[cfg(feature = "fixed")]
+impls!(@display RelaxedU16F16);
+#[cfg(feature = "fixed")]
+impls!(@serde RelaxedU16F16);
+#[cfg(feature = "fixed")]
+impls!(@from_str RelaxedU16F16);
+#[cfg(feature = "fixed")]
+impls!(@minicbor RelaxedU16F16, U16F16::to_bits, U16F16::from_bits);
+#[cfg(feature = "fixed")]
+impls!(@bincode RelaxedU16F16, U16F16::to_bits, U16F16::from_bits);
+#[cfg(feature = "fixed")]
+impls!(@borsh RelaxedU16F16, U16F16::to_bits, U16F16::from_bits);
+
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+impls!(RelaxedDuration: AtomicU64, core::time::Duration, duration::to_nanos, duration::from_nanos);
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+impls!(@atomic RelaxedDuration: AtomicU64);
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+impls!(@serde RelaxedDuration);
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+impls!(@schemars RelaxedDuration);
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+impls!(@minicbor RelaxedDuration);
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+impls!(@bincode RelaxedDuration);
+// borsh has no `Duration` impl, so the nanoseconds are encoded instead
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+impls!(@borsh RelaxedDuration, duration::to_nanos, duration::from_nanos);
+
+#[cfg(feature = "half")]
+impls!(RelaxedF16: AtomicU16, f16, f16::to_bits, f16::from_bits);
+#[cfg(feature = "half")]
+impls!(@atomic RelaxedF16: AtomicU16);
+#[cfg(feature = "half")]
+impls!(@display RelaxedF16);
+#[cfg(feature = "half")]
+impls!(@serde RelaxedF16);
+#[cfg(feature = "half")]
+impls!(@from_str RelaxedF16);
+#[cfg(feature = "half")]
+impls!(@minicbor RelaxedF16, f16::to_f32, f16::from_f32);
+#[cfg(feature = "half")]
+impls!(@bincode RelaxedF16, f16::to_f32, f16::from_f32);
+#[cfg(feature = "half")]
+impls!(@borsh RelaxedF16, f16::to_f32, f16::from_f32);
+
+macro_rules! int_impls {
+    ($name:ident: $inner:ty) => {
+        #[allow(clippy::declare_interior_mutable_const)]
+        impl $name {
+            /// A new value of zero, usable to initialize arrays in const context.
+            pub const ZERO: Self = Self::new(0);
+            /// A new value of the largest representable number.
+            pub const MAX: Self = Self::new(<$inner>::MAX);
+            /// A new value of the smallest representable number.
+            pub const MIN: Self = Self::new(<$inner>::MIN);
+
+            /// Like [`Default::default`], but usable in const context.
+            #[inline(always)]
+            pub const fn default_const() -> Self {
+                Self::ZERO
+            }
+
+            /// Clamps the value into `min..=max`, returning the previous value.
+            ///
+            /// Nothing is stored if the value is already in range.
+            ///
+            /// # Panics
+            /// Panics if `min > max`.
+            #[inline]
+            pub fn fetch_clamp(&self, min: $inner, max: $inner) -> $inner {
+                assert!(min <= max, "min must not be greater than max");
+                match self.fetch_update(|current| {
+                    let clamped = current.clamp(min, max);
+                    (clamped != current).then_some(clamped)
+                }) {
+                    Ok(previous) | Err(previous) => previous,
+                }
+            }
+        }
+    };
+}
+
+macro_rules! unsigned_impls {
+    ($name:ident: $inner:ty) => {
+        impl $name {
+            /// Adds `delta`, wrapping around on overflow, returning the previous value.
+            #[allow(dead_code)]
+            #[inline(always)]
+            pub(crate) fn fetch_add_wrapping(&self, delta: $inner) -> $inner {
+                let previous = self.0.fetch_add(delta, Ordering::Relaxed);
+                #[cfg(feature = "trace")]
+                self.trace(previous, previous.wrapping_add(delta));
+                previous
+            }
+            /// Sets the bits of `mask`, returning the previous value.
+            #[allow(dead_code)]
+            #[inline(always)]
+            pub(crate) fn fetch_or_bits(&self, mask: $inner) -> $inner {
+                let previous = self.0.fetch_or(mask, Ordering::Relaxed);
+                #[cfg(feature = "trace")]
+                self.trace(previous, previous | mask);
+                previous
+            }
+            /// Adds `delta` modulo `modulus`, returning the previous value.
+            ///
+            /// The stored value is reduced modulo `modulus` as well,
+            /// so it stays a valid index into a ring of `modulus` elements.
+            ///
+            /// # Panics
+            /// Panics if `modulus` is zero.
+            #[inline]
+            pub fn fetch_add_mod(&self, delta: $inner, modulus: $inner) -> $inner {
+                assert!(modulus != 0, "modulus must be non-zero");
+                let delta = delta % modulus;
+                self.get_and_update(|current| {
+                    let current = current % modulus;
+                    // `current + delta` may overflow, so wrap before adding
+                    if current >= modulus - delta {
+                        current - (modulus - delta)
+                    } else {
+                        current + delta
+                    }
+                })
+            }
+            /// Increments modulo `N`, returning the previous value.
+            ///
+            /// Like [`fetch_add_mod(1, N)`](Self::fetch_add_mod),
+            /// but uses a mask instead of a division if `N` is a power of two.
+            #[inline]
+            pub fn fetch_incr_wrap<const N: $inner>(&self) -> $inner {
+                const { assert!(N != 0, "N must be non-zero") };
+                if N.is_power_of_two() {
+                    self.get_and_update(|current| current.wrapping_add(1) & (N - 1))
+                } else {
+                    self.fetch_add_mod(1, N)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! signed_impls {
+    ($name:ident: $inner:ty) => {
+        impl $name {
+            /// Negates the value, returning the previous value.
+            ///
+            /// Wraps around on overflow, so `MIN` stays `MIN`.
+            #[inline]
+            pub fn fetch_neg(&self) -> $inner {
+                self.get_and_update(<$inner>::wrapping_neg)
+            }
+            /// Replaces the value with its absolute value, returning the previous value.
+            ///
+            /// Wraps around on overflow, so `MIN` stays `MIN`.
+            /// Nothing is stored if the value is already non-negative.
+            #[inline]
+            pub fn fetch_abs(&self) -> $inner {
+                match self.fetch_update(|current| (current < 0).then(|| current.wrapping_abs())) {
+                    Ok(previous) | Err(previous) => previous,
+                }
+            }
+        }
+    };
+}
+
+unsigned_impls!(RelaxedU8: u8);
+unsigned_impls!(RelaxedU16: u16);
+unsigned_impls!(RelaxedU32: u32);
+#[cfg(feature = "portable-atomic")]
+unsigned_impls!(RelaxedU128: u128);
+
+signed_impls!(RelaxedI8: i8);
+signed_impls!(RelaxedI16: i16);
+signed_impls!(RelaxedI32: i32);
+#[cfg(feature = "portable-atomic")]
+signed_impls!(RelaxedI128: i128);
+
+int_impls!(RelaxedU8: u8);
+int_impls!(RelaxedU16: u16);
+int_impls!(RelaxedU32: u32);
+
+int_impls!(RelaxedI8: i8);
+int_impls!(RelaxedI16: i16);
+int_impls!(RelaxedI32: i32);
+
+#[cfg(feature = "portable-atomic")]
+int_impls!(RelaxedU128: u128);
+#[cfg(feature = "portable-atomic")]
+int_impls!(RelaxedI128: i128);
+
+#[allow(clippy::declare_interior_mutable_const)]
 impl RelaxedBool {
+    /// A new value of `true`.
+    pub const TRUE: Self = Self::new(true);
+    /// A new value of `false`, usable to initialize arrays in const context.
+    pub const FALSE: Self = Self::new(false);
+
+    /// Like [`Default::default`], but usable in const context.
+    #[inline(always)]
+    pub const fn default_const() -> Self {
+        Self::FALSE
+    }
+
+    /// Busy-waits until the flag equals `val`.
+    #[inline]
+    pub fn spin_until(&self, val: bool) {
+        self.spin_while(|current| current != val);
+    }
+    /// Like [`spin_until`](Self::spin_until), but gives up after `max_spins` iterations.
+    /// Returns whether the flag reached `val`.
+    #[inline]
+    pub fn spin_until_bounded(&self, val: bool, max_spins: u32) -> bool {
+        self.spin_while_bounded(max_spins, |current| current != val)
+            .is_ok()
+    }
+
     /// Performs a logical "not" operation on the current value, and sets the new value to the result.
     /// Returns the previous value.
     pub fn fetch_not(&self) -> bool {
-        self.0.fetch_not(Ordering::Relaxed)
+        let previous = self.0.fetch_xor(true, Ordering::Relaxed);
+        #[cfg(feature = "trace")]
+        self.trace(previous, !previous);
+        previous
+    }
+
+    /// Sets the flag to `true` and returns a guard that resets it to `false` when dropped.
+    pub fn guard(&self) -> BusyGuard<'_> {
+        self.set(true);
+        BusyGuard(self)
+    }
+    /// Sets the flag to `true` and returns a guard that resets it to `false` when dropped.
+    /// Returns `None` if the flag was already set.
+    pub fn try_guard(&self) -> Option<BusyGuard<'_>> {
+        if self.swap(true) {
+            None
+        } else {
+            Some(BusyGuard(self))
+        }
+    }
+}
+
+impl RelaxedU32 {
+    /// Returns byte lane `i`, where lane 0 is the least significant byte.
+    ///
+    /// # Panics
+    /// Panics if `i` is greater than 3.
+    ///
+    /// ```
+    /// use relaxed::RelaxedU32;
+    ///
+    /// static CHANNELS: RelaxedU32 = RelaxedU32::new(0);
+    ///
+    /// assert_eq!(CHANNELS.set_byte(2, 0xaa), 0);
+    /// CHANNELS.set_byte(0, 0x11);
+    /// assert_eq!(CHANNELS.get_byte(2), 0xaa);
+    /// assert_eq!(CHANNELS.get_bytes(), [0x11, 0, 0xaa, 0]);
+    /// ```
+    #[inline(always)]
+    pub fn get_byte(&self, i: usize) -> u8 {
+        self.get_bytes()[i]
+    }
+    /// Replaces byte lane `i` without disturbing the other lanes, returning the previous byte.
+    ///
+    /// # Panics
+    /// Panics if `i` is greater than 3.
+    #[inline]
+    pub fn set_byte(&self, i: usize, val: u8) -> u8 {
+        assert!(i < 4, "byte lane out of range");
+        let shift = i * 8;
+        let previous =
+            self.get_and_update(|current| (current & !(0xff << shift)) | ((val as u32) << shift));
+        (previous >> shift) as u8
+    }
+    /// Returns all four byte lanes, lane 0 first.
+    #[inline(always)]
+    pub fn get_bytes(&self) -> [u8; 4] {
+        self.get().to_le_bytes()
+    }
+}
+
+impl RelaxedF32 {
+    /// Adds to the current value, returning the previous value.
+    #[inline(always)]
+    pub fn fetch_add(&self, val: f32) -> f32 {
+        #[cfg(feature = "atomic_float")]
+        let previous = self.0.fetch_add(val, Ordering::Relaxed);
+        #[cfg(not(feature = "atomic_float"))]
+        let previous = self.get_and_update(|current| current + val);
+        #[cfg(all(feature = "atomic_float", feature = "trace"))]
+        self.trace(previous, previous + val);
+        previous
+    }
+    /// Subtracts from the current value, returning the previous value.
+    #[inline(always)]
+    pub fn fetch_sub(&self, val: f32) -> f32 {
+        #[cfg(feature = "atomic_float")]
+        let previous = self.0.fetch_sub(val, Ordering::Relaxed);
+        #[cfg(not(feature = "atomic_float"))]
+        let previous = self.get_and_update(|current| current - val);
+        #[cfg(all(feature = "atomic_float", feature = "trace"))]
+        self.trace(previous, previous - val);
+        previous
+    }
+    /// Multiplies the current value, returning the previous value.
+    #[inline(always)]
+    pub fn fetch_mul(&self, val: f32) -> f32 {
+        self.get_and_update(|current| current * val)
+    }
+    /// Divides the current value, returning the previous value.
+    #[inline(always)]
+    pub fn fetch_div(&self, val: f32) -> f32 {
+        self.get_and_update(|current| current / val)
+    }
+    /// Negates the value, returning the previous value.
+    #[inline(always)]
+    pub fn fetch_neg(&self) -> f32 {
+        self.get_and_update(|current| -current)
+    }
+    /// Stores the maximum of the value and `val`, returning the previous value.
+    ///
+    /// Follows [`f32::max`]: a NaN `val` leaves the value unchanged,
+    /// and a stored NaN is replaced by `val`.
+    /// Nothing is stored if the value doesn't change.
+    ///
+    /// ```
+    /// use relaxed::RelaxedF32;
+    ///
+    /// static PEAK_CURRENT: RelaxedF32 = RelaxedF32::new(f32::NAN);
+    ///
+    /// PEAK_CURRENT.fetch_max(1.5);
+    /// PEAK_CURRENT.fetch_max(f32::NAN);
+    /// assert_eq!(PEAK_CURRENT.fetch_max(0.5), 1.5);
+    /// assert_eq!(PEAK_CURRENT.get(), 1.5);
+    /// ```
+    #[inline]
+    pub fn fetch_max(&self, val: f32) -> f32 {
+        self.fetch_update_if_changed(|current| current.max(val))
+    }
+    /// Stores the minimum of the value and `val`, returning the previous value.
+    ///
+    /// Follows [`f32::min`]: a NaN `val` leaves the value unchanged,
+    /// and a stored NaN is replaced by `val`.
+    /// Nothing is stored if the value doesn't change.
+    #[inline]
+    pub fn fetch_min(&self, val: f32) -> f32 {
+        self.fetch_update_if_changed(|current| current.min(val))
+    }
+    /// Clamps the value into `min..=max`, returning the previous value.
+    ///
+    /// Nothing is stored if the value is already in range. A NaN value stays NaN.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, `min` is NaN or `max` is NaN.
+    ///
+    /// ```
+    /// use relaxed::RelaxedF32;
+    ///
+    /// static SETPOINT: RelaxedF32 = RelaxedF32::new(80.0);
+    ///
+    /// // the upper limit was lowered at runtime
+    /// assert_eq!(SETPOINT.fetch_clamp(0.0, 60.0), 80.0);
+    /// assert_eq!(SETPOINT.get(), 60.0);
+    /// ```
+    #[inline]
+    pub fn fetch_clamp(&self, min: f32, max: f32) -> f32 {
+        assert!(min <= max, "min must not be greater than max");
+        self.fetch_update_if_changed(|current| current.clamp(min, max))
+    }
+    /// Like [`get_and_update`](Self::get_and_update), but doesn't store a bitwise identical result.
+    #[inline(always)]
+    fn fetch_update_if_changed(&self, mut f: impl FnMut(f32) -> f32) -> f32 {
+        match self.fetch_update(|current| {
+            let new = f(current);
+            (new.to_bits() != current.to_bits()).then_some(new)
+        }) {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+    /// Returns `true` if the value differs from `other` by at most `eps`.
+    #[inline(always)]
+    pub fn approx_eq(&self, other: f32, eps: f32) -> bool {
+        (self.get() - other).abs() <= eps
+    }
+    /// Returns `true` if the value is NaN.
+    #[inline(always)]
+    pub fn is_nan(&self) -> bool {
+        self.get().is_nan()
+    }
+    /// Returns `true` if the value is neither infinite nor NaN.
+    #[inline(always)]
+    pub fn is_finite(&self) -> bool {
+        self.get().is_finite()
+    }
+}
+
+/// Resets a [`RelaxedBool`] to `false` when dropped, see [`RelaxedBool::guard`].
+#[must_use = "the flag is reset immediately if the guard is not held"]
+#[derive(Debug)]
+pub struct BusyGuard<'a>(&'a RelaxedBool);
+
+impl Drop for BusyGuard<'_> {
+    fn drop(&mut self) {
+        self.0.set(false)
     }
 }
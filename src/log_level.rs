@@ -0,0 +1,133 @@
+use core::fmt::{Debug, Display, Formatter};
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use log::{Level, LevelFilter};
+
+use crate::{Relaxable, RelaxedValue};
+
+fn from_u8(raw: u8) -> LevelFilter {
+    match raw {
+        0 => LevelFilter::Off,
+        1 => LevelFilter::Error,
+        2 => LevelFilter::Warn,
+        3 => LevelFilter::Info,
+        4 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// A [`LevelFilter`] that always uses `Ordering::Relaxed` for access.
+///
+/// Useful as a runtime adjustable log level, e.g. from a debug console.
+#[repr(transparent)]
+pub struct RelaxedLogLevel(AtomicU8);
+
+impl RelaxedLogLevel {
+    /// Create a new value.
+    #[inline(always)]
+    pub const fn new(level: LevelFilter) -> Self {
+        RelaxedLogLevel(AtomicU8::new(level as u8))
+    }
+
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> LevelFilter {
+        from_u8(self.0.load(Ordering::Relaxed))
+    }
+    /// Performs an atomic store with relaxed ordering.
+    #[inline(always)]
+    pub fn set(&self, level: LevelFilter) {
+        self.0.store(level as u8, Ordering::Relaxed)
+    }
+    /// Stores a value, returning the previous value.
+    #[inline(always)]
+    pub fn swap(&self, level: LevelFilter) -> LevelFilter {
+        from_u8(self.0.swap(level as u8, Ordering::Relaxed))
+    }
+    /// Stores `new` if the current value is `current`.
+    /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+    #[inline(always)]
+    pub fn compare_exchange(
+        &self,
+        current: LevelFilter,
+        new: LevelFilter,
+    ) -> Result<LevelFilter, LevelFilter> {
+        self.0
+            .compare_exchange(
+                current as u8,
+                new as u8,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
+            .map(from_u8)
+            .map_err(from_u8)
+    }
+    /// Returns whether messages of `level` pass the filter.
+    #[inline(always)]
+    pub fn enabled(&self, level: Level) -> bool {
+        level <= self.get()
+    }
+
+    /// Installs the current value as the global maximum log level of the `log` crate.
+    pub fn install(&self) {
+        log::set_max_level(self.get())
+    }
+    /// Performs [`set`](Self::set) followed by [`install`](Self::install).
+    pub fn set_and_install(&self, level: LevelFilter) {
+        self.set(level);
+        self.install()
+    }
+}
+
+impl RelaxedValue for RelaxedLogLevel {
+    type Value = LevelFilter;
+
+    #[inline(always)]
+    fn get(&self) -> LevelFilter {
+        RelaxedLogLevel::get(self)
+    }
+    #[inline(always)]
+    fn set(&self, level: LevelFilter) {
+        RelaxedLogLevel::set(self, level)
+    }
+    #[inline(always)]
+    fn swap(&self, level: LevelFilter) -> LevelFilter {
+        RelaxedLogLevel::swap(self, level)
+    }
+    #[inline(always)]
+    fn compare_exchange(
+        &self,
+        current: LevelFilter,
+        new: LevelFilter,
+    ) -> Result<LevelFilter, LevelFilter> {
+        RelaxedLogLevel::compare_exchange(self, current, new)
+    }
+}
+
+impl Relaxable for LevelFilter {
+    type Relaxed = RelaxedLogLevel;
+}
+
+impl Default for RelaxedLogLevel {
+    fn default() -> Self {
+        RelaxedLogLevel::new(LevelFilter::Off)
+    }
+}
+
+impl Debug for RelaxedLogLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl Display for RelaxedLogLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl From<LevelFilter> for RelaxedLogLevel {
+    fn from(level: LevelFilter) -> Self {
+        RelaxedLogLevel::new(level)
+    }
+}
@@ -0,0 +1,181 @@
+/// Declares statics of the matching relaxed wrapper type.
+///
+/// ```
+/// use relaxed::relaxed_static;
+///
+/// relaxed_static! {
+///     /// Number of received packets.
+///     pub static PACKET_COUNT: u32 = 0;
+///     static FAULT: bool = false;
+/// }
+///
+/// PACKET_COUNT.set(3);
+/// FAULT.set(true);
+/// assert_eq!(PACKET_COUNT.get(), 3);
+/// ```
+#[macro_export]
+macro_rules! relaxed_static {
+    ($($(#[$attr:meta])* $vis:vis static $name:ident: $ty:ty = $val:expr;)*) => {
+        $(
+            $(#[$attr])*
+            $vis static $name: <$ty as $crate::Relaxable>::Relaxed =
+                <<$ty as $crate::Relaxable>::Relaxed>::new($val);
+        )*
+    };
+}
+
+/// Declares a plain struct and a mirrored struct of relaxed wrappers, without a proc-macro.
+///
+/// The first struct holds the plain values, the second one has the same fields
+/// wrapped in their relaxed types, plus `new`, `snapshot` and `store` to convert between the two.
+/// Every field is accessed on its own, so a snapshot is not atomic as a whole.
+///
+/// ```
+/// use relaxed::relaxed_struct;
+///
+/// relaxed_struct! {
+///     /// Motor settings.
+///     #[derive(Clone, Copy, Debug, PartialEq)]
+///     pub struct Settings {
+///         pub gain: f32,
+///         pub enabled: bool,
+///     }
+///     /// Motor settings shared between the control loop and the console.
+///     #[derive(Debug)]
+///     pub struct SharedSettings;
+/// }
+///
+/// static SETTINGS: SharedSettings = SharedSettings::new(Settings {
+///     gain: 1.0,
+///     enabled: false,
+/// });
+///
+/// SETTINGS.enabled.set(true);
+/// assert_eq!(SETTINGS.snapshot(), Settings { gain: 1.0, enabled: true });
+/// ```
+#[macro_export]
+macro_rules! relaxed_struct {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $plain:ident {
+            $($(#[$field_attr:meta])* $field_vis:vis $field:ident: $ty:ty),* $(,)?
+        }
+        $(#[$relaxed_attr:meta])*
+        $relaxed_vis:vis struct $relaxed:ident;
+    ) => {
+        $(#[$attr])*
+        $vis struct $plain {
+            $($(#[$field_attr])* $field_vis $field: $ty),*
+        }
+
+        $(#[$relaxed_attr])*
+        $relaxed_vis struct $relaxed {
+            $($(#[$field_attr])* $field_vis $field: <$ty as $crate::Relaxable>::Relaxed),*
+        }
+
+        impl $relaxed {
+            /// Create a new value.
+            #[allow(dead_code)]
+            $relaxed_vis const fn new(val: $plain) -> Self {
+                $relaxed {
+                    $($field: <<$ty as $crate::Relaxable>::Relaxed>::new(val.$field)),*
+                }
+            }
+            /// Loads every field with relaxed ordering.
+            #[allow(dead_code)]
+            $relaxed_vis fn snapshot(&self) -> $plain {
+                $plain {
+                    $($field: $crate::RelaxedValue::get(&self.$field)),*
+                }
+            }
+            /// Stores every field with relaxed ordering.
+            #[allow(dead_code)]
+            $relaxed_vis fn store(&self, val: &$plain) {
+                $($crate::RelaxedValue::set(&self.$field, val.$field);)*
+            }
+        }
+
+        impl ::core::convert::From<$plain> for $relaxed {
+            fn from(val: $plain) -> Self {
+                $relaxed::new(val)
+            }
+        }
+    };
+}
+
+/// Declares a module of named static [`RelaxedU32`](crate::RelaxedU32) counters,
+/// with an `iter()` function over the name and counter of every static.
+///
+/// ```
+/// use relaxed::counters;
+///
+/// counters! {
+///     pub mod stats {
+///         /// Received packets.
+///         RX_PACKETS,
+///         TX_PACKETS,
+///     }
+/// }
+///
+/// stats::RX_PACKETS.set(2);
+///
+/// for (name, counter) in stats::iter() {
+///     println!("{name}: {}", counter.get());
+/// }
+/// let names: Vec<_> = stats::iter().map(|(name, _)| name).collect();
+/// assert_eq!(names, ["RX_PACKETS", "TX_PACKETS"]);
+/// ```
+#[macro_export]
+macro_rules! counters {
+    (
+        $(#[$attr:meta])*
+        $vis:vis mod $group:ident {
+            $($(#[$counter_attr:meta])* $counter:ident),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis mod $group {
+            $(
+                $(#[$counter_attr])*
+                pub static $counter: $crate::RelaxedU32 = $crate::RelaxedU32::new(0);
+            )*
+
+            /// Returns an iterator over the name and counter of every static in this group.
+            #[allow(dead_code)]
+            pub fn iter(
+            ) -> impl ::core::iter::Iterator<Item = (&'static str, &'static $crate::RelaxedU32)> {
+                [$((::core::stringify!($counter), &$counter)),*].into_iter()
+            }
+        }
+    };
+}
+
+/// Counts how often this line runs, in a hidden static [`CallSite`](crate::CallSite),
+/// returning the new count.
+///
+/// Every call site that ran at least once can be dumped with [`call_sites`](crate::call_sites).
+///
+/// ```
+/// use relaxed::{call_sites, count_here};
+///
+/// fn poll() {
+///     count_here!();
+/// }
+///
+/// for _ in 0..3 {
+///     poll();
+/// }
+///
+/// for site in call_sites() {
+///     println!("{}:{}: {}", site.file(), site.line(), site.count());
+/// }
+/// assert!(call_sites().any(|site| site.count() == 3));
+/// ```
+#[macro_export]
+macro_rules! count_here {
+    () => {{
+        static SITE: $crate::CallSite =
+            $crate::CallSite::new(::core::file!(), ::core::line!(), ::core::module_path!());
+        SITE.hit()
+    }};
+}
@@ -0,0 +1,66 @@
+//! [`metrics`] handle implementations, so relaxed values can be registered with a recorder.
+
+use metrics::{CounterFn, GaugeFn};
+
+use crate::{RelaxedF32, RelaxedI16, RelaxedI32, RelaxedI8, RelaxedU16, RelaxedU32, RelaxedU8};
+
+macro_rules! counter_impls {
+    ($name:ident: $inner:ty) => {
+        /// Increments wrap around on overflow, `absolute` saturates at the maximum value.
+        impl CounterFn for $name {
+            fn increment(&self, value: u64) {
+                self.fetch_add_wrapping(value as $inner);
+            }
+
+            fn absolute(&self, value: u64) {
+                let value = <$inner>::try_from(value).unwrap_or(<$inner>::MAX);
+                let _ = self.fetch_update(|current| (value > current).then_some(value));
+            }
+        }
+    };
+}
+
+macro_rules! gauge_impls {
+    ($name:ident: $inner:ty) => {
+        /// Values outside the range of the wrapped type saturate.
+        impl GaugeFn for $name {
+            fn increment(&self, value: f64) {
+                self.get_and_update(|v| (v as f64 + value) as $inner);
+            }
+
+            fn decrement(&self, value: f64) {
+                self.get_and_update(|v| (v as f64 - value) as $inner);
+            }
+
+            fn set(&self, value: f64) {
+                $name::set(self, value as $inner)
+            }
+        }
+    };
+}
+
+counter_impls!(RelaxedU8: u8);
+counter_impls!(RelaxedU16: u16);
+counter_impls!(RelaxedU32: u32);
+
+gauge_impls!(RelaxedU8: u8);
+gauge_impls!(RelaxedU16: u16);
+gauge_impls!(RelaxedU32: u32);
+
+gauge_impls!(RelaxedI8: i8);
+gauge_impls!(RelaxedI16: i16);
+gauge_impls!(RelaxedI32: i32);
+
+impl GaugeFn for RelaxedF32 {
+    fn increment(&self, value: f64) {
+        self.fetch_add(value as f32);
+    }
+
+    fn decrement(&self, value: f64) {
+        self.fetch_sub(value as f32);
+    }
+
+    fn set(&self, value: f64) {
+        RelaxedF32::set(self, value as f32)
+    }
+}
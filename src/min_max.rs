@@ -0,0 +1,100 @@
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Minimum in the high half, maximum in the low half, the minimum above the maximum when empty.
+const EMPTY: u32 = 0xffff_0000;
+
+const fn unpack(bits: u32) -> Option<(u16, u16)> {
+    let (min, max) = ((bits >> 16) as u16, bits as u16);
+    if min > max {
+        None
+    } else {
+        Some((min, max))
+    }
+}
+
+/// The running minimum and maximum of `u16` samples, packed into a single atomic.
+///
+/// Each sample is recorded with a single compare and exchange,
+/// so readers always see a consistent `(min, max)` pair.
+///
+/// ```
+/// use relaxed::RelaxedMinMaxU16;
+///
+/// static ADC_RANGE: RelaxedMinMaxU16 = RelaxedMinMaxU16::new();
+///
+/// assert_eq!(ADC_RANGE.get(), None);
+/// for sample in [512, 100, 900] {
+///     ADC_RANGE.record(sample);
+/// }
+/// assert_eq!(ADC_RANGE.reset(), Some((100, 900)));
+/// assert_eq!(ADC_RANGE.get(), None);
+/// ```
+#[repr(transparent)]
+pub struct RelaxedMinMaxU16(AtomicU32);
+
+impl RelaxedMinMaxU16 {
+    /// Create a new tracker without samples.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        RelaxedMinMaxU16(AtomicU32::new(EMPTY))
+    }
+
+    /// Returns `(min, max)` of the samples recorded so far, or `None` if there are none.
+    #[inline(always)]
+    pub fn get(&self) -> Option<(u16, u16)> {
+        unpack(self.0.load(Ordering::Relaxed))
+    }
+    /// Returns the minimum of the samples recorded so far.
+    #[inline(always)]
+    pub fn min(&self) -> Option<u16> {
+        self.get().map(|(min, _)| min)
+    }
+    /// Returns the maximum of the samples recorded so far.
+    #[inline(always)]
+    pub fn max(&self) -> Option<u16> {
+        self.get().map(|(_, max)| max)
+    }
+    /// Adds a sample, only writing if it extends the range.
+    pub fn record(&self, sample: u16) {
+        let _ = self
+            .0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+                let (min, max) = ((bits >> 16) as u16, bits as u16);
+                let (new_min, new_max) = (min.min(sample), max.max(sample));
+                if (new_min, new_max) == (min, max) {
+                    None
+                } else {
+                    Some((new_min as u32) << 16 | new_max as u32)
+                }
+            });
+    }
+    /// Forgets all samples, returning the previous `(min, max)`.
+    #[inline(always)]
+    pub fn reset(&self) -> Option<(u16, u16)> {
+        unpack(self.0.swap(EMPTY, Ordering::Relaxed))
+    }
+}
+
+impl Default for RelaxedMinMaxU16 {
+    fn default() -> Self {
+        RelaxedMinMaxU16::new()
+    }
+}
+
+impl Debug for RelaxedMinMaxU16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RelaxedMinMaxU16 {
+    /// Serializes the `(min, max)` pair, or `None` without samples.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
@@ -0,0 +1,81 @@
+use core::convert::Infallible;
+
+use embedded_hal::digital::{ErrorType, InputPin, OutputPin, StatefulOutputPin};
+
+use crate::RelaxedBool;
+
+/// An [`embedded_hal`] pin backed by a [`RelaxedBool`], for testing drivers.
+///
+/// The test observes and drives the pin state through the shared `RelaxedBool`,
+/// from any thread or task. `true` is high and `false` is low.
+///
+/// ```
+/// use embedded_hal::digital::{InputPin, OutputPin};
+/// use relaxed::{MockPin, RelaxedBool};
+///
+/// let state = RelaxedBool::new(false);
+/// let mut pin = MockPin::new(&state);
+///
+/// pin.set_high().unwrap();
+/// assert!(state.get());
+///
+/// state.set(false);
+/// assert!(pin.is_low().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MockPin<'a> {
+    state: &'a RelaxedBool,
+}
+
+impl<'a> MockPin<'a> {
+    /// Create a new pin backed by `state`.
+    pub const fn new(state: &'a RelaxedBool) -> Self {
+        MockPin { state }
+    }
+
+    /// Returns the value backing the pin.
+    pub fn state(&self) -> &'a RelaxedBool {
+        self.state
+    }
+}
+
+impl ErrorType for MockPin<'_> {
+    type Error = Infallible;
+}
+
+impl InputPin for MockPin<'_> {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.state.get())
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.state.get())
+    }
+}
+
+impl OutputPin for MockPin<'_> {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.state.set(false);
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.state.set(true);
+        Ok(())
+    }
+}
+
+impl StatefulOutputPin for MockPin<'_> {
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.state.get())
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.state.get())
+    }
+
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.state.fetch_not();
+        Ok(())
+    }
+}
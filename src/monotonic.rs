@@ -0,0 +1,103 @@
+use core::fmt::{Debug, Display, Formatter};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// A `u32` that can only move forward, e.g. for sequence numbers or uptime counters.
+///
+/// There is no way to store a smaller value, so accidental resets don't compile.
+///
+/// ```
+/// use relaxed::RelaxedMonotonicU32;
+///
+/// static SEQUENCE: RelaxedMonotonicU32 = RelaxedMonotonicU32::new(0);
+///
+/// assert!(SEQUENCE.increment());
+/// assert!(SEQUENCE.advance_to(10));
+/// assert!(!SEQUENCE.advance_to(5));
+/// assert_eq!(SEQUENCE.get(), 10);
+/// ```
+#[derive(Default)]
+#[repr(transparent)]
+pub struct RelaxedMonotonicU32(AtomicU32);
+
+impl RelaxedMonotonicU32 {
+    /// Create a new value.
+    #[inline(always)]
+    pub const fn new(val: u32) -> Self {
+        RelaxedMonotonicU32(AtomicU32::new(val))
+    }
+
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> u32 {
+        self.0.load(Ordering::Relaxed)
+    }
+    /// Raises the value to `val`, returning whether it moved forward.
+    #[inline(always)]
+    pub fn advance_to(&self, val: u32) -> bool {
+        self.0.fetch_max(val, Ordering::Relaxed) < val
+    }
+    /// Adds one, returning whether the value moved forward.
+    /// The value saturates at `u32::MAX` instead of wrapping around.
+    #[inline(always)]
+    pub fn increment(&self) -> bool {
+        self.0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |val| {
+                val.checked_add(1)
+            })
+            .is_ok()
+    }
+}
+
+impl Debug for RelaxedMonotonicU32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl Display for RelaxedMonotonicU32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
+impl From<u32> for RelaxedMonotonicU32 {
+    fn from(val: u32) -> Self {
+        Self::new(val)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RelaxedMonotonicU32 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RelaxedMonotonicU32 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::Deserialize::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for RelaxedMonotonicU32 {
+    fn inline_schema() -> bool {
+        <u32 as schemars::JsonSchema>::inline_schema()
+    }
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        <u32 as schemars::JsonSchema>::schema_name()
+    }
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        <u32 as schemars::JsonSchema>::schema_id()
+    }
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        <u32 as schemars::JsonSchema>::json_schema(generator)
+    }
+}
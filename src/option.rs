@@ -0,0 +1,197 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::{
+    Relaxable, RelaxedF32, RelaxedI16, RelaxedI32, RelaxedI8, RelaxedU16, RelaxedU32, RelaxedU8,
+    RelaxedValue,
+};
+
+/// Types with a bit pattern that is never used as a value, so it can represent `None`
+/// in a [`RelaxedOption`].
+pub trait Sentinel: Relaxable {
+    /// The value representing `None`.
+    const NONE: Self;
+    /// A relaxed wrapper containing [`NONE`](Self::NONE).
+    const NONE_RELAXED: Self::Relaxed;
+
+    /// Returns `true` if `self` represents `None`.
+    fn is_none(self) -> bool;
+}
+
+macro_rules! sentinel_impls {
+    ($inner:ty: $relaxed:ident, $none:expr) => {
+        impl Sentinel for $inner {
+            const NONE: Self = $none;
+            #[allow(clippy::declare_interior_mutable_const)]
+            const NONE_RELAXED: $relaxed = $relaxed::new($none);
+
+            #[inline(always)]
+            fn is_none(self) -> bool {
+                self == $none
+            }
+        }
+    };
+}
+
+sentinel_impls!(u8: RelaxedU8, u8::MAX);
+sentinel_impls!(u16: RelaxedU16, u16::MAX);
+sentinel_impls!(u32: RelaxedU32, u32::MAX);
+
+sentinel_impls!(i8: RelaxedI8, i8::MIN);
+sentinel_impls!(i16: RelaxedI16, i16::MIN);
+sentinel_impls!(i32: RelaxedI32, i32::MIN);
+
+/// Every NaN represents `None`, the canonical NaN is stored for it.
+impl Sentinel for f32 {
+    const NONE: Self = f32::NAN;
+    #[allow(clippy::declare_interior_mutable_const)]
+    const NONE_RELAXED: RelaxedF32 = RelaxedF32::new(f32::NAN);
+
+    #[inline(always)]
+    fn is_none(self) -> bool {
+        self.is_nan()
+    }
+}
+
+/// An optional value that always uses `Ordering::Relaxed` for access,
+/// without taking more space than `T`.
+///
+/// `None` is stored as the [`Sentinel`] value of `T`, e.g. `u32::MAX` or NaN,
+/// which therefore can't be stored as `Some`.
+///
+/// ```
+/// use relaxed::RelaxedOption;
+///
+/// static SELECTED: RelaxedOption<u32> = RelaxedOption::none();
+///
+/// assert!(SELECTED.set_if_none(3));
+/// assert!(!SELECTED.set_if_none(4));
+/// assert_eq!(SELECTED.take(), Some(3));
+/// assert_eq!(SELECTED.get(), None);
+/// ```
+#[repr(transparent)]
+pub struct RelaxedOption<T: Sentinel>(T::Relaxed);
+
+/// An optional `f32`, with NaN representing `None`,
+/// e.g. for a sensor value that is not available yet.
+///
+/// `None` is stored as the canonical NaN, but every NaN reads as `None`,
+/// so no NaN payload can be stored as `Some`.
+///
+/// ```
+/// use relaxed::RelaxedOptionF32;
+///
+/// static TEMPERATURE: RelaxedOptionF32 = RelaxedOptionF32::none();
+///
+/// assert_eq!(TEMPERATURE.get(), None);
+/// assert!(TEMPERATURE.set(Some(f32::NAN)).is_err());
+/// assert!(TEMPERATURE.set(Some(f32::from_bits(0x7fc0_0001))).is_err());
+/// TEMPERATURE.set(Some(21.5)).unwrap();
+/// assert_eq!(TEMPERATURE.take(), Some(21.5));
+/// ```
+pub type RelaxedOptionF32 = RelaxedOption<f32>;
+
+impl<T: Sentinel> RelaxedOption<T> {
+    /// Create a new `None` value.
+    #[inline(always)]
+    pub const fn none() -> Self {
+        RelaxedOption(T::NONE_RELAXED)
+    }
+
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> Option<T> {
+        Some(self.0.get()).filter(|val| !val.is_none())
+    }
+    /// Performs an atomic store with relaxed ordering.
+    ///
+    /// Returns `Err(val)` without storing anything if `val` is the sentinel value.
+    #[inline(always)]
+    pub fn set(&self, val: Option<T>) -> Result<(), T> {
+        match val {
+            Some(val) if val.is_none() => Err(val),
+            Some(val) => {
+                self.0.set(val);
+                Ok(())
+            }
+            None => {
+                self.0.set(T::NONE);
+                Ok(())
+            }
+        }
+    }
+    /// Takes the value out, leaving `None` in its place.
+    #[inline(always)]
+    pub fn take(&self) -> Option<T> {
+        Some(self.0.swap(T::NONE)).filter(|val| !val.is_none())
+    }
+    /// Stores `val` if the current value is `None`. Returns `true` if `val` was stored.
+    ///
+    /// The sentinel value is never stored.
+    #[inline(always)]
+    pub fn set_if_none(&self, val: T) -> bool {
+        !val.is_none() && self.0.compare_exchange(T::NONE, val).is_ok()
+    }
+    /// Returns `true` if the value is `Some`.
+    #[inline(always)]
+    pub fn is_some(&self) -> bool {
+        self.get().is_some()
+    }
+    /// Returns `true` if the value is `None`.
+    #[inline(always)]
+    pub fn is_none(&self) -> bool {
+        self.get().is_none()
+    }
+}
+
+impl<T: Sentinel> Default for RelaxedOption<T> {
+    fn default() -> Self {
+        RelaxedOption::none()
+    }
+}
+
+impl<T: Sentinel + Debug> Debug for RelaxedOption<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Sentinel + serde::Serialize> serde::Serialize for RelaxedOption<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Sentinel + serde::Deserialize<'de>> serde::Deserialize<'de> for RelaxedOption<T> {
+    /// Fails for `Some` of the sentinel value, which can't be represented.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let option = Self::none();
+        option
+            .set(serde::Deserialize::deserialize(deserializer)?)
+            .map_err(|_| serde::de::Error::custom("the sentinel value can't be stored"))?;
+        Ok(option)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: Sentinel + schemars::JsonSchema> schemars::JsonSchema for RelaxedOption<T> {
+    fn inline_schema() -> bool {
+        <Option<T> as schemars::JsonSchema>::inline_schema()
+    }
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        <Option<T> as schemars::JsonSchema>::schema_name()
+    }
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        <Option<T> as schemars::JsonSchema>::schema_id()
+    }
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        <Option<T> as schemars::JsonSchema>::json_schema(generator)
+    }
+}
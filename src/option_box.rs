@@ -0,0 +1,116 @@
+use alloc::boxed::Box;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// An `Option<Box<T>>` that hands owned values between threads.
+///
+/// Unlike the other wrappers this uses acquire/release ordering,
+/// so the contents of a box put by one thread are visible to the thread taking it.
+///
+/// ```
+/// use relaxed::RelaxedOptionBox;
+///
+/// let slot = RelaxedOptionBox::none();
+/// assert_eq!(slot.put(Box::new(1)), None);
+/// assert_eq!(slot.put(Box::new(2)), Some(Box::new(1)));
+/// assert_eq!(slot.take(), Some(Box::new(2)));
+/// assert_eq!(slot.take(), None);
+/// ```
+///
+/// Any thread sharing the slot can take the box, so sharing it requires `T: Send`:
+///
+/// ```compile_fail
+/// use std::sync::MutexGuard;
+///
+/// use relaxed::RelaxedOptionBox;
+///
+/// fn assert_sync<T: Sync>() {}
+/// // `MutexGuard` is `Sync`, but must not be dropped on another thread
+/// assert_sync::<RelaxedOptionBox<MutexGuard<'static, i32>>>();
+/// ```
+pub struct RelaxedOptionBox<T> {
+    ptr: AtomicPtr<T>,
+    _box: PhantomData<*mut T>,
+}
+
+// SAFETY: the slot owns the box, like a `Mutex<Option<Box<T>>>`
+unsafe impl<T: Send> Send for RelaxedOptionBox<T> {}
+// SAFETY: shared access only moves whole boxes in and out, which needs `T: Send`
+unsafe impl<T: Send> Sync for RelaxedOptionBox<T> {}
+
+impl<T> RelaxedOptionBox<T> {
+    /// Create a new empty slot.
+    pub const fn none() -> Self {
+        RelaxedOptionBox {
+            ptr: AtomicPtr::new(ptr::null_mut()),
+            _box: PhantomData,
+        }
+    }
+    /// Create a new value.
+    pub fn new(val: Option<Box<T>>) -> Self {
+        RelaxedOptionBox {
+            ptr: AtomicPtr::new(into_raw(val)),
+            _box: PhantomData,
+        }
+    }
+
+    /// Stores a box, returning the previous one.
+    pub fn put(&self, val: Box<T>) -> Option<Box<T>> {
+        self.swap(Some(val))
+    }
+    /// Takes the box, leaving the slot empty.
+    pub fn take(&self) -> Option<Box<T>> {
+        self.swap(None)
+    }
+    /// Stores a value, returning the previous one.
+    pub fn swap(&self, val: Option<Box<T>>) -> Option<Box<T>> {
+        let previous = self.ptr.swap(into_raw(val), Ordering::AcqRel);
+        // SAFETY: the pointer is either null or came from `Box::into_raw` and is no longer stored
+        unsafe { from_raw(previous) }
+    }
+    /// Returns `true` if the slot currently holds a box.
+    pub fn is_some(&self) -> bool {
+        !self.ptr.load(Ordering::Relaxed).is_null()
+    }
+    /// Returns `true` if the slot is currently empty.
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+}
+
+fn into_raw<T>(val: Option<Box<T>>) -> *mut T {
+    val.map_or(ptr::null_mut(), Box::into_raw)
+}
+
+unsafe fn from_raw<T>(ptr: *mut T) -> Option<Box<T>> {
+    (!ptr.is_null()).then(|| Box::from_raw(ptr))
+}
+
+impl<T> Drop for RelaxedOptionBox<T> {
+    fn drop(&mut self) {
+        // SAFETY: the pointer is either null or came from `Box::into_raw`
+        unsafe { drop(from_raw(*self.ptr.get_mut())) }
+    }
+}
+
+impl<T> Default for RelaxedOptionBox<T> {
+    fn default() -> Self {
+        RelaxedOptionBox::none()
+    }
+}
+
+impl<T> Debug for RelaxedOptionBox<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RelaxedOptionBox")
+            .field("is_some", &self.is_some())
+            .finish()
+    }
+}
+
+impl<T> From<Option<Box<T>>> for RelaxedOptionBox<T> {
+    fn from(val: Option<Box<T>>) -> Self {
+        RelaxedOptionBox::new(val)
+    }
+}
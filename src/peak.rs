@@ -0,0 +1,132 @@
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::Ordering;
+
+use crate::{RelaxedF32, RelaxedU16};
+
+/// A peak detector for `f32` magnitudes whose peak decays when it is read.
+///
+/// The peak starts at zero, so samples are expected to be non-negative, e.g. absolute levels.
+///
+/// ```
+/// use relaxed::RelaxedPeakF32;
+///
+/// static LEVEL: RelaxedPeakF32 = RelaxedPeakF32::new();
+///
+/// LEVEL.feed(0.5);
+/// LEVEL.feed(0.8);
+/// LEVEL.feed(0.2);
+/// assert_eq!(LEVEL.read_and_decay(0.5), 0.8);
+/// assert_eq!(LEVEL.peak(), 0.4);
+/// ```
+#[derive(Default)]
+#[repr(transparent)]
+pub struct RelaxedPeakF32(RelaxedF32);
+
+impl RelaxedPeakF32 {
+    /// Create a new detector with a peak of zero.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        RelaxedPeakF32(RelaxedF32::new(0.0))
+    }
+
+    /// Raises the peak to `sample` if it is higher. NaN samples are ignored.
+    pub fn feed(&self, sample: f32) {
+        self.0.fetch_max(sample);
+    }
+    /// Returns the peak without decaying it.
+    #[inline(always)]
+    pub fn peak(&self) -> f32 {
+        self.0.get()
+    }
+    /// Returns the peak and multiplies it by `factor`.
+    pub fn read_and_decay(&self, factor: f32) -> f32 {
+        self.0.get_and_update(|peak| peak * factor)
+    }
+    /// Returns the peak and resets it to zero.
+    #[inline(always)]
+    pub fn reset(&self) -> f32 {
+        self.0.swap(0.0)
+    }
+}
+
+impl Debug for RelaxedPeakF32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RelaxedPeakF32").field(&self.peak()).finish()
+    }
+}
+
+/// A peak detector for `u16` samples whose peak decays when it is read.
+///
+/// ```
+/// use relaxed::RelaxedPeakU16;
+///
+/// static LOAD: RelaxedPeakU16 = RelaxedPeakU16::new();
+///
+/// LOAD.feed(300);
+/// LOAD.feed(1000);
+/// assert_eq!(LOAD.read_and_decay(0.75), 1000);
+/// assert_eq!(LOAD.peak(), 750);
+/// ```
+#[derive(Default)]
+#[repr(transparent)]
+pub struct RelaxedPeakU16(RelaxedU16);
+
+impl RelaxedPeakU16 {
+    /// Create a new detector with a peak of zero.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        RelaxedPeakU16(RelaxedU16::new(0))
+    }
+
+    /// Raises the peak to `sample` if it is higher.
+    #[inline(always)]
+    pub fn feed(&self, sample: u16) {
+        let previous = self.0 .0.fetch_max(sample, Ordering::Relaxed);
+        #[cfg(feature = "trace")]
+        self.0.trace(previous, previous.max(sample));
+        #[cfg(not(feature = "trace"))]
+        let _ = previous;
+    }
+    /// Returns the peak without decaying it.
+    #[inline(always)]
+    pub fn peak(&self) -> u16 {
+        self.0.get()
+    }
+    /// Returns the peak and multiplies it by `factor`, rounding down and saturating.
+    pub fn read_and_decay(&self, factor: f32) -> u16 {
+        self.0.get_and_update(|peak| (peak as f32 * factor) as u16)
+    }
+    /// Returns the peak and resets it to zero.
+    #[inline(always)]
+    pub fn reset(&self) -> u16 {
+        self.0.swap(0)
+    }
+}
+
+impl Debug for RelaxedPeakU16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RelaxedPeakU16").field(&self.peak()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RelaxedPeakF32 {
+    /// Serializes the peak without decaying it.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.peak().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RelaxedPeakU16 {
+    /// Serializes the peak without decaying it.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.peak().serialize(serializer)
+    }
+}
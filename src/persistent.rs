@@ -0,0 +1,79 @@
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::RelaxedValue;
+
+const MAGIC: u32 = 0x5afe_c0de;
+
+/// A relaxed value guarded by a validity word, meant to survive soft resets.
+///
+/// Place it in a section that isn't initialized at startup, e.g. `.uninit` with `cortex-m-rt`.
+/// After a cold boot the contents are garbage and the validity word almost certainly
+/// doesn't match, so [`get_if_valid`](Self::get_if_valid) returns `None`
+/// until the first [`set`](Self::set).
+///
+/// ```
+/// use relaxed::{PersistentCell, RelaxedU32};
+///
+/// #[cfg_attr(target_os = "none", link_section = ".uninit.BOOT_COUNT")]
+/// static BOOT_COUNT: PersistentCell<RelaxedU32> = PersistentCell::new(RelaxedU32::new(0));
+///
+/// let boots = BOOT_COUNT.get_if_valid().unwrap_or(0) + 1;
+/// BOOT_COUNT.set(boots);
+/// assert_eq!(BOOT_COUNT.get_if_valid(), Some(1));
+/// ```
+pub struct PersistentCell<R> {
+    magic: AtomicU32,
+    value: R,
+}
+
+impl<R: RelaxedValue> PersistentCell<R> {
+    /// Create a new invalid cell.
+    ///
+    /// `value` is only used if the cell is placed in initialized memory.
+    pub const fn new(value: R) -> Self {
+        PersistentCell {
+            magic: AtomicU32::new(0),
+            value,
+        }
+    }
+
+    /// Returns whether the cell holds a value written by [`set`](Self::set).
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool {
+        self.magic.load(Ordering::Acquire) == MAGIC
+    }
+    /// Returns the stored value, or `None` if the cell is not valid.
+    #[inline(always)]
+    pub fn get_if_valid(&self) -> Option<R::Value> {
+        // the value may be garbage and must not be loaded before it is known to be valid
+        if self.is_valid() {
+            Some(self.value.get())
+        } else {
+            None
+        }
+    }
+    /// Stores a value and marks the cell as valid.
+    #[inline(always)]
+    pub fn set(&self, val: R::Value) {
+        self.value.set(val);
+        // a reset must not leave a valid marker with the value not written yet
+        self.magic.store(MAGIC, Ordering::Release);
+    }
+    /// Marks the cell as not valid.
+    #[inline(always)]
+    pub fn invalidate(&self) {
+        self.magic.store(0, Ordering::Relaxed)
+    }
+}
+
+impl<R: RelaxedValue> Debug for PersistentCell<R>
+where
+    R::Value: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PersistentCell")
+            .field(&self.get_if_valid())
+            .finish()
+    }
+}
@@ -0,0 +1,225 @@
+//! Prometheus text exposition of relaxed counters and gauges.
+//!
+//! ```
+//! use relaxed::prometheus::{self, Metric};
+//! use relaxed::{RelaxedF32, RelaxedU32};
+//!
+//! static REQUESTS: RelaxedU32 = RelaxedU32::new(0);
+//! static TEMPERATURE: RelaxedF32 = RelaxedF32::new(0.0);
+//!
+//! static METRICS: [Metric; 2] = [
+//!     Metric::counter("requests_total", &REQUESTS).with_help("Handled requests."),
+//!     Metric::gauge("temperature_celsius", &TEMPERATURE),
+//! ];
+//!
+//! REQUESTS.set(3);
+//! TEMPERATURE.set(21.5);
+//!
+//! let text = prometheus::to_string(&METRICS);
+//! let lines: Vec<&str> = text.lines().collect();
+//! assert_eq!(lines, [
+//!     "# HELP requests_total Handled requests.",
+//!     "# TYPE requests_total counter",
+//!     "requests_total 3",
+//!     "# TYPE temperature_celsius gauge",
+//!     "temperature_celsius 21.5",
+//! ]);
+//! ```
+
+use std::string::String;
+
+use core::fmt::Write;
+
+use crate::{
+    RelaxedBool, RelaxedCheckedCounter, RelaxedDeltaCounter, RelaxedF32, RelaxedI16, RelaxedI32,
+    RelaxedI8, RelaxedScaled, RelaxedU16, RelaxedU32, RelaxedU8,
+};
+
+/// Relaxed values that can be exposed as a Prometheus sample.
+pub trait Sample: Sync {
+    /// Performs an atomic load with relaxed ordering and converts the value to `f64`.
+    fn sample(&self) -> f64;
+}
+
+macro_rules! sample_impls {
+    ($($name:ty),*) => {
+        $(impl Sample for $name {
+            fn sample(&self) -> f64 {
+                self.get().into()
+            }
+        })*
+    };
+}
+
+sample_impls!(RelaxedU8, RelaxedU16, RelaxedU32, RelaxedI8, RelaxedI16, RelaxedI32, RelaxedF32);
+
+impl Sample for RelaxedBool {
+    fn sample(&self) -> f64 {
+        u8::from(self.get()).into()
+    }
+}
+
+impl Sample for RelaxedCheckedCounter {
+    fn sample(&self) -> f64 {
+        self.get().into()
+    }
+}
+
+impl Sample for RelaxedDeltaCounter {
+    fn sample(&self) -> f64 {
+        self.total().into()
+    }
+}
+
+impl<const SCALE: u32> Sample for RelaxedScaled<SCALE> {
+    fn sample(&self) -> f64 {
+        f64::from(self.get()) / f64::from(SCALE)
+    }
+}
+
+/// The Prometheus metric type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MetricType {
+    /// A value that only goes up.
+    Counter,
+    /// A value that can go up and down.
+    Gauge,
+}
+
+impl MetricType {
+    fn as_str(self) -> &'static str {
+        match self {
+            MetricType::Counter => "counter",
+            MetricType::Gauge => "gauge",
+        }
+    }
+}
+
+/// A named value to expose.
+#[derive(Clone, Copy)]
+pub struct Metric<'a> {
+    name: &'a str,
+    help: Option<&'a str>,
+    kind: MetricType,
+    value: &'a dyn Sample,
+}
+
+impl<'a> Metric<'a> {
+    /// Create a new metric of type counter.
+    pub const fn counter(name: &'a str, value: &'a dyn Sample) -> Self {
+        Metric {
+            name,
+            help: None,
+            kind: MetricType::Counter,
+            value,
+        }
+    }
+    /// Create a new metric of type gauge.
+    pub const fn gauge(name: &'a str, value: &'a dyn Sample) -> Self {
+        Metric {
+            name,
+            help: None,
+            kind: MetricType::Gauge,
+            value,
+        }
+    }
+    /// Adds a `# HELP` line.
+    pub const fn with_help(mut self, help: &'a str) -> Self {
+        self.help = Some(help);
+        self
+    }
+
+    /// Returns the name of the metric.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+    /// Returns the type of the metric.
+    pub fn kind(&self) -> MetricType {
+        self.kind
+    }
+}
+
+impl core::fmt::Debug for Metric<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Metric")
+            .field("name", &self.name)
+            .field("kind", &self.kind)
+            .field("value", &self.value.sample())
+            .finish()
+    }
+}
+
+fn encode_one(
+    out: &mut impl Write,
+    name: &str,
+    help: Option<&str>,
+    kind: MetricType,
+    value: f64,
+) -> core::fmt::Result {
+    if let Some(help) = help {
+        out.write_str("# HELP ")?;
+        out.write_str(name)?;
+        out.write_char(' ')?;
+        for c in help.chars() {
+            match c {
+                '\\' => out.write_str("\\\\")?,
+                '\n' => out.write_str("\\n")?,
+                c => out.write_char(c)?,
+            }
+        }
+        out.write_char('\n')?;
+    }
+    writeln!(out, "# TYPE {name} {}", kind.as_str())?;
+    if value == f64::INFINITY {
+        writeln!(out, "{name} +Inf")
+    } else if value == f64::NEG_INFINITY {
+        writeln!(out, "{name} -Inf")
+    } else {
+        writeln!(out, "{name} {value}")
+    }
+}
+
+/// Renders `metrics` in the Prometheus text format.
+pub fn encode(out: &mut impl Write, metrics: &[Metric<'_>]) -> core::fmt::Result {
+    for metric in metrics {
+        encode_one(
+            out,
+            metric.name,
+            metric.help,
+            metric.kind,
+            metric.value.sample(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Renders `metrics` in the Prometheus text format into a new `String`.
+pub fn to_string(metrics: &[Metric<'_>]) -> String {
+    let mut out = String::new();
+    encode(&mut out, metrics).expect("writing to a String never fails");
+    out
+}
+
+/// Renders every entry of `registry` as a gauge in the Prometheus text format.
+#[cfg(feature = "registry")]
+pub fn encode_registry(
+    out: &mut impl Write,
+    registry: &crate::registry::Registry,
+) -> core::fmt::Result {
+    use crate::registry::Value;
+
+    for entry in registry.iter() {
+        let value = match entry.get() {
+            Value::Bool(v) => u8::from(v).into(),
+            Value::U8(v) => v.into(),
+            Value::U16(v) => v.into(),
+            Value::U32(v) => v.into(),
+            Value::I8(v) => v.into(),
+            Value::I16(v) => v.into(),
+            Value::I32(v) => v.into(),
+            Value::F32(v) => v.into(),
+        };
+        encode_one(out, entry.name(), None, MetricType::Gauge, value)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use core::fmt::{Debug, Formatter};
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::{Backoff, Relaxable, RelaxedValue};
+
+/// A raw pointer that always uses `Ordering::Relaxed` for access,
+/// with the strict provenance operations of [`AtomicPtr`].
+///
+/// Pointer arithmetic keeps the provenance of the stored pointer instead of round-tripping
+/// through an integer, so cursors into shared buffers stay valid under Miri.
+/// Relaxed ordering doesn't make the pointee visible to other threads,
+/// publish it with a separate release/acquire pair if needed.
+///
+/// ```
+/// use relaxed::RelaxedPtr;
+///
+/// let mut buf = [0u16; 8];
+/// let cursor = RelaxedPtr::new(buf.as_mut_ptr());
+///
+/// let first = cursor.fetch_ptr_add(2);
+/// assert_eq!(first, buf.as_mut_ptr());
+/// assert_eq!(cursor.get(), buf.as_mut_ptr().wrapping_add(2));
+///
+/// cursor.fetch_byte_add(4);
+/// assert_eq!(cursor.get(), buf.as_mut_ptr().wrapping_add(4));
+/// ```
+#[repr(transparent)]
+pub struct RelaxedPtr<T>(AtomicPtr<T>);
+
+impl<T> RelaxedPtr<T> {
+    /// Create a new null pointer.
+    #[inline(always)]
+    pub const fn null() -> Self {
+        RelaxedPtr(AtomicPtr::new(ptr::null_mut()))
+    }
+    /// Create a new value.
+    #[inline(always)]
+    pub const fn new(ptr: *mut T) -> Self {
+        RelaxedPtr(AtomicPtr::new(ptr))
+    }
+
+    /// Returns the underlying atomic, e.g. to pass it to an API that requires one.
+    ///
+    /// Accesses through the atomic bypass the wrapper and may use any ordering.
+    #[inline(always)]
+    pub const fn as_atomic(&self) -> &AtomicPtr<T> {
+        &self.0
+    }
+    /// Views an atomic as a relaxed wrapper, without copying it.
+    #[inline(always)]
+    pub const fn from_atomic(atomic: &AtomicPtr<T>) -> &Self {
+        // SAFETY: `Self` is a `repr(transparent)` wrapper of the atomic
+        unsafe { &*(atomic as *const AtomicPtr<T> as *const Self) }
+    }
+
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> *mut T {
+        self.0.load(Ordering::Relaxed)
+    }
+    /// Performs an atomic store with relaxed ordering.
+    #[inline(always)]
+    pub fn set(&self, ptr: *mut T) {
+        self.0.store(ptr, Ordering::Relaxed)
+    }
+    /// Stores a pointer, returning the previous pointer.
+    #[inline(always)]
+    pub fn swap(&self, ptr: *mut T) -> *mut T {
+        self.0.swap(ptr, Ordering::Relaxed)
+    }
+    /// Stores `new` if the current pointer is `current`.
+    /// Returns `Ok(previous_pointer)` on success, else `Err(current_pointer)`.
+    #[inline(always)]
+    pub fn compare_exchange(&self, current: *mut T, new: *mut T) -> Result<*mut T, *mut T> {
+        self.0
+            .compare_exchange(current, new, Ordering::Relaxed, Ordering::Relaxed)
+    }
+    /// Fetches the pointer, and applies `f` to it that returns an optional new pointer.
+    /// Returns `Ok(previous_pointer)` if `f` returned `Some`, else `Err(previous_pointer)`.
+    ///
+    /// `f` may be called multiple times if the pointer was changed by other threads
+    /// in the meantime, but the new pointer is stored atomically.
+    #[inline(always)]
+    pub fn fetch_update(
+        &self,
+        mut f: impl FnMut(*mut T) -> Option<*mut T>,
+    ) -> Result<*mut T, *mut T> {
+        let mut backoff = Backoff::new();
+        let mut current = self.get();
+        while let Some(new) = f(current) {
+            match self
+                .0
+                .compare_exchange_weak(current, new, Ordering::Relaxed, Ordering::Relaxed)
+            {
+                Ok(previous) => return Ok(previous),
+                Err(actual) => {
+                    backoff.spin();
+                    current = actual;
+                }
+            }
+        }
+        Err(current)
+    }
+
+    /// Offsets the pointer by `count` elements of `T`, wrapping around,
+    /// returning the previous pointer.
+    #[inline(always)]
+    pub fn fetch_ptr_add(&self, count: usize) -> *mut T {
+        self.0.fetch_ptr_add(count, Ordering::Relaxed)
+    }
+    /// Offsets the pointer backwards by `count` elements of `T`, wrapping around,
+    /// returning the previous pointer.
+    #[inline(always)]
+    pub fn fetch_ptr_sub(&self, count: usize) -> *mut T {
+        self.0.fetch_ptr_sub(count, Ordering::Relaxed)
+    }
+    /// Offsets the pointer by `bytes`, wrapping around, returning the previous pointer.
+    #[inline(always)]
+    pub fn fetch_byte_add(&self, bytes: usize) -> *mut T {
+        self.0.fetch_byte_add(bytes, Ordering::Relaxed)
+    }
+    /// Offsets the pointer backwards by `bytes`, wrapping around, returning the previous pointer.
+    #[inline(always)]
+    pub fn fetch_byte_sub(&self, bytes: usize) -> *mut T {
+        self.0.fetch_byte_sub(bytes, Ordering::Relaxed)
+    }
+    /// Performs a bitwise "or" on the address, keeping the provenance,
+    /// returning the previous pointer.
+    #[inline(always)]
+    pub fn fetch_or(&self, bits: usize) -> *mut T {
+        self.0.fetch_or(bits, Ordering::Relaxed)
+    }
+    /// Performs a bitwise "and" on the address, keeping the provenance,
+    /// returning the previous pointer.
+    #[inline(always)]
+    pub fn fetch_and(&self, bits: usize) -> *mut T {
+        self.0.fetch_and(bits, Ordering::Relaxed)
+    }
+    /// Performs a bitwise "xor" on the address, keeping the provenance,
+    /// returning the previous pointer.
+    #[inline(always)]
+    pub fn fetch_xor(&self, bits: usize) -> *mut T {
+        self.0.fetch_xor(bits, Ordering::Relaxed)
+    }
+    /// Atomically replaces the address with the result of `f`, keeping the provenance,
+    /// like [`pointer::map_addr`]. Returns the previous pointer.
+    ///
+    /// `f` may be called multiple times if the pointer was changed by other threads
+    /// in the meantime.
+    #[inline(always)]
+    pub fn fetch_map_addr(&self, mut f: impl FnMut(usize) -> usize) -> *mut T {
+        match self.fetch_update(|ptr| Some(ptr.map_addr(&mut f))) {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+}
+
+impl<T> RelaxedValue for RelaxedPtr<T> {
+    type Value = *mut T;
+
+    #[inline(always)]
+    fn get(&self) -> *mut T {
+        RelaxedPtr::get(self)
+    }
+    #[inline(always)]
+    fn set(&self, ptr: *mut T) {
+        RelaxedPtr::set(self, ptr)
+    }
+    #[inline(always)]
+    fn swap(&self, ptr: *mut T) -> *mut T {
+        RelaxedPtr::swap(self, ptr)
+    }
+    #[inline(always)]
+    fn compare_exchange(&self, current: *mut T, new: *mut T) -> Result<*mut T, *mut T> {
+        RelaxedPtr::compare_exchange(self, current, new)
+    }
+}
+
+impl<T> Relaxable for *mut T {
+    type Relaxed = RelaxedPtr<T>;
+}
+
+impl<T> Default for RelaxedPtr<T> {
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T> Debug for RelaxedPtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl<T> From<*mut T> for RelaxedPtr<T> {
+    fn from(ptr: *mut T) -> Self {
+        Self::new(ptr)
+    }
+}
+
+impl<T> AsRef<AtomicPtr<T>> for RelaxedPtr<T> {
+    fn as_ref(&self) -> &AtomicPtr<T> {
+        self.as_atomic()
+    }
+}
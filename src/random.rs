@@ -0,0 +1,77 @@
+use rand::{Rng, RngExt};
+
+use crate::{
+    RelaxedBool, RelaxedF32, RelaxedI16, RelaxedI32, RelaxedI8, RelaxedU16, RelaxedU32, RelaxedU8,
+};
+
+macro_rules! random_impls {
+    ($name:ident: $inner:ty) => {
+        impl $name {
+            /// Create a new value drawn uniformly from the whole range of the type.
+            pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::new(rng.random())
+            }
+            /// Adds a random offset in `-max_delta..=max_delta`, saturating at the numeric bounds,
+            /// returning the previous value.
+            ///
+            /// The offset is drawn once, before the update.
+            pub fn jitter<R: Rng + ?Sized>(&self, rng: &mut R, max_delta: $inner) -> $inner {
+                let max_delta = (max_delta as i64).abs();
+                let delta = rng.random_range(-max_delta..=max_delta);
+                self.get_and_update(|current| {
+                    (current as i64 + delta).clamp(<$inner>::MIN as i64, <$inner>::MAX as i64)
+                        as $inner
+                })
+            }
+        }
+    };
+}
+
+random_impls!(RelaxedU8: u8);
+random_impls!(RelaxedU16: u16);
+random_impls!(RelaxedU32: u32);
+
+random_impls!(RelaxedI8: i8);
+random_impls!(RelaxedI16: i16);
+random_impls!(RelaxedI32: i32);
+
+impl RelaxedBool {
+    /// Create a new value that is `true` with a probability of 50%.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(rng.random())
+    }
+}
+
+impl RelaxedF32 {
+    /// Create a new value drawn uniformly from `0.0..1.0`.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(rng.random())
+    }
+    /// Adds a random offset in `-max_delta..=max_delta`, returning the previous value.
+    ///
+    /// The offset is drawn once, before the update.
+    /// `max_delta` is limited to `f32::MAX / 2`, so the width of the range stays finite,
+    /// and a NaN `max_delta` leaves the value unchanged.
+    ///
+    /// ```
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    /// use relaxed::RelaxedF32;
+    ///
+    /// let level = RelaxedF32::new(1.0);
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    ///
+    /// assert_eq!(level.jitter(&mut rng, f32::NAN), 1.0);
+    /// assert_eq!(level.get(), 1.0);
+    /// level.jitter(&mut rng, f32::INFINITY);
+    /// assert!(level.get().is_finite());
+    /// ```
+    pub fn jitter<R: Rng + ?Sized>(&self, rng: &mut R, max_delta: f32) -> f32 {
+        if max_delta.is_nan() {
+            return self.get();
+        }
+        let max_delta = max_delta.abs().min(f32::MAX / 2.0);
+        let delta = rng.random_range(-max_delta..=max_delta);
+        self.fetch_add(delta)
+    }
+}
@@ -0,0 +1,119 @@
+use core::cell::UnsafeCell;
+use core::fmt::{Debug, Formatter};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Integer types that can be accessed by a [`RelaxedReg`] with a single volatile instruction.
+pub trait RegValue: sealed::Sealed + Copy {}
+
+macro_rules! reg_value_impls {
+    ($($inner:ty),*) => {
+        $(
+            impl sealed::Sealed for $inner {}
+            impl RegValue for $inner {}
+        )*
+    };
+}
+
+reg_value_impls!(u8, u16, u32, i8, i16, i32);
+
+/// A register-like value whose every access is a volatile load or store.
+///
+/// The plain relaxed types only guarantee atomicity towards other threads,
+/// so the compiler may merge, reorder or elide accesses that no other thread could observe.
+/// Hardware is not part of that model: use `RelaxedReg` for memory-mapped peripheral mirrors
+/// and status words shared with a DMA engine, where each access must happen exactly as written.
+/// For values only shared between threads and interrupt handlers, prefer the plain types.
+///
+/// Loads and stores are single instructions on all supported targets,
+/// but there are no atomic read-modify-write operations,
+/// [`update`](Self::update) is a separate load and store.
+/// DMA buffers may additionally need cache maintenance, which this type doesn't do.
+///
+/// Volatile accesses are not atomic in the Rust memory model,
+/// so concurrent accesses from two threads would be a data race.
+/// Like `vcell::VolatileCell` the type is therefore not `Sync`:
+/// access it from a single context, or wrap it, e.g. in a `critical_section::Mutex`,
+/// to share it.
+///
+/// ```
+/// use relaxed::RelaxedReg;
+///
+/// let dma_status = RelaxedReg::new(0u32);
+///
+/// dma_status.set(0b1);
+/// assert_eq!(dma_status.get(), 0b1);
+/// ```
+///
+/// ```compile_fail
+/// use relaxed::RelaxedReg;
+///
+/// static DMA_STATUS: RelaxedReg<u32> = RelaxedReg::new(0);
+/// ```
+#[repr(transparent)]
+pub struct RelaxedReg<T>(UnsafeCell<T>);
+
+impl<T: RegValue> RelaxedReg<T> {
+    /// Create a new value.
+    #[inline(always)]
+    pub const fn new(val: T) -> Self {
+        RelaxedReg(UnsafeCell::new(val))
+    }
+
+    /// Views the memory at `ptr`, e.g. a peripheral register, as a `RelaxedReg`.
+    ///
+    /// # Safety
+    /// `ptr` must be non-null, aligned for `T` and valid for volatile reads and writes
+    /// for the lifetime `'a`.
+    #[inline(always)]
+    pub const unsafe fn from_ptr<'a>(ptr: *mut T) -> &'a Self {
+        // SAFETY: `RelaxedReg<T>` is a transparent wrapper around `T`,
+        // the caller guarantees that `ptr` is valid
+        unsafe { &*(ptr as *const Self) }
+    }
+
+    /// Returns a raw pointer to the value.
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.0.get()
+    }
+    /// Performs a volatile load.
+    #[inline(always)]
+    pub fn get(&self) -> T {
+        // SAFETY: the pointer is valid and aligned
+        unsafe { self.0.get().read_volatile() }
+    }
+    /// Performs a volatile store.
+    #[inline(always)]
+    pub fn set(&self, val: T) {
+        // SAFETY: the pointer is valid and aligned
+        unsafe { self.0.get().write_volatile(val) }
+    }
+    /// Performs a volatile load, applies `f` and stores the result with a volatile store.
+    ///
+    /// This is not atomic, concurrent writes between the load and the store are lost.
+    #[inline(always)]
+    pub fn update(&self, f: impl FnOnce(T) -> T) {
+        self.set(f(self.get()))
+    }
+}
+
+impl<T: RegValue + Default> Default for RelaxedReg<T> {
+    fn default() -> Self {
+        RelaxedReg::new(T::default())
+    }
+}
+
+impl<T: RegValue + Debug> Debug for RelaxedReg<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl<T: RegValue> From<T> for RelaxedReg<T> {
+    fn from(val: T) -> Self {
+        RelaxedReg::new(val)
+    }
+}
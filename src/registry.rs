@@ -0,0 +1,286 @@
+//! A registry of named relaxed values for debug tooling.
+//!
+//! Debug shells, RTT viewers and tuning UIs can list, read and write every registered value
+//! without knowing the concrete types.
+//!
+//! ```
+//! use relaxed::{registry, RelaxedBool, RelaxedU32};
+//! use relaxed::registry::Value;
+//!
+//! static PACKET_COUNT: RelaxedU32 = RelaxedU32::new(0);
+//! static FAULT: RelaxedBool = RelaxedBool::new(false);
+//!
+//! registry! {
+//!     static DEBUG_VALUES = [PACKET_COUNT, FAULT];
+//! }
+//!
+//! DEBUG_VALUES.find("FAULT").unwrap().set(Value::Bool(true)).unwrap();
+//! assert!(FAULT.get());
+//!
+//! for entry in DEBUG_VALUES.iter() {
+//!     let _ = (entry.name(), entry.kind(), entry.get());
+//! }
+//! ```
+
+use core::fmt::{Display, Formatter};
+
+pub mod protocol;
+
+use crate::{
+    RelaxedBool, RelaxedF32, RelaxedI16, RelaxedI32, RelaxedI8, RelaxedU16, RelaxedU32, RelaxedU8,
+};
+
+/// The type of a registered value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Kind {
+    Bool,
+    U8,
+    U16,
+    U32,
+    I8,
+    I16,
+    I32,
+    F32,
+}
+
+/// A dynamically typed value read from or written to the registry.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(missing_docs)]
+pub enum Value {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    F32(f32),
+}
+
+impl Value {
+    /// Returns the type of the value.
+    pub fn kind(&self) -> Kind {
+        match self {
+            Value::Bool(_) => Kind::Bool,
+            Value::U8(_) => Kind::U8,
+            Value::U16(_) => Kind::U16,
+            Value::U32(_) => Kind::U32,
+            Value::I8(_) => Kind::I8,
+            Value::I16(_) => Kind::I16,
+            Value::I32(_) => Kind::I32,
+            Value::F32(_) => Kind::F32,
+        }
+    }
+    /// Parses `s` as a value of type `kind`.
+    pub fn parse(kind: Kind, s: &str) -> Result<Value, ParseValueError> {
+        let value = match kind {
+            Kind::Bool => s.parse().map(Value::Bool).ok(),
+            Kind::U8 => s.parse().map(Value::U8).ok(),
+            Kind::U16 => s.parse().map(Value::U16).ok(),
+            Kind::U32 => s.parse().map(Value::U32).ok(),
+            Kind::I8 => s.parse().map(Value::I8).ok(),
+            Kind::I16 => s.parse().map(Value::I16).ok(),
+            Kind::I32 => s.parse().map(Value::I32).ok(),
+            Kind::F32 => s.parse().map(Value::F32).ok(),
+        };
+        value.ok_or(ParseValueError { kind })
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Value::Bool(v) => v.fmt(f),
+            Value::U8(v) => v.fmt(f),
+            Value::U16(v) => v.fmt(f),
+            Value::U32(v) => v.fmt(f),
+            Value::I8(v) => v.fmt(f),
+            Value::I16(v) => v.fmt(f),
+            Value::I32(v) => v.fmt(f),
+            Value::F32(v) => v.fmt(f),
+        }
+    }
+}
+
+/// Error returned when writing a [`Value`] of the wrong [`Kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KindMismatch {
+    /// The kind of the registered value.
+    pub expected: Kind,
+    /// The kind of the value that was written.
+    pub found: Kind,
+}
+
+impl Display for KindMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "expected a {:?} value, found {:?}",
+            self.expected, self.found
+        )
+    }
+}
+
+/// Error returned when a string can't be parsed as a [`Value`] of the requested [`Kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseValueError {
+    /// The kind of value that was expected.
+    pub kind: Kind,
+}
+
+impl Display for ParseValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid {:?} value", self.kind)
+    }
+}
+
+/// Relaxed values that can be added to a [`Registry`].
+pub trait Register: Sync {
+    /// Returns the type of the value.
+    fn kind(&self) -> Kind;
+    /// Performs an atomic load with relaxed ordering.
+    fn get_value(&self) -> Value;
+    /// Performs an atomic store with relaxed ordering, if `value` has the right [`Kind`].
+    fn set_value(&self, value: Value) -> Result<(), KindMismatch>;
+}
+
+macro_rules! register_impls {
+    ($name:ident: $kind:ident) => {
+        impl Register for $name {
+            fn kind(&self) -> Kind {
+                Kind::$kind
+            }
+
+            fn get_value(&self) -> Value {
+                Value::$kind(self.get())
+            }
+
+            fn set_value(&self, value: Value) -> Result<(), KindMismatch> {
+                match value {
+                    Value::$kind(v) => {
+                        self.set(v);
+                        Ok(())
+                    }
+                    _ => Err(KindMismatch {
+                        expected: Kind::$kind,
+                        found: value.kind(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+register_impls!(RelaxedBool: Bool);
+
+register_impls!(RelaxedU8: U8);
+register_impls!(RelaxedU16: U16);
+register_impls!(RelaxedU32: U32);
+
+register_impls!(RelaxedI8: I8);
+register_impls!(RelaxedI16: I16);
+register_impls!(RelaxedI32: I32);
+
+register_impls!(RelaxedF32: F32);
+
+/// A named value in a [`Registry`].
+#[derive(Clone, Copy)]
+pub struct Entry {
+    name: &'static str,
+    value: &'static dyn Register,
+}
+
+impl Entry {
+    /// Create a new entry.
+    pub const fn new(name: &'static str, value: &'static dyn Register) -> Self {
+        Entry { name, value }
+    }
+
+    /// Returns the name the value was registered with.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    /// Returns the type of the value.
+    pub fn kind(&self) -> Kind {
+        self.value.kind()
+    }
+    /// Performs an atomic load with relaxed ordering.
+    pub fn get(&self) -> Value {
+        self.value.get_value()
+    }
+    /// Performs an atomic store with relaxed ordering, if `value` has the right [`Kind`].
+    pub fn set(&self, value: Value) -> Result<(), KindMismatch> {
+        self.value.set_value(value)
+    }
+    /// Parses `s` as the type of the value and stores the result.
+    /// The value is left unchanged if parsing fails.
+    pub fn set_from_str(&self, s: &str) -> Result<(), ParseValueError> {
+        let value = Value::parse(self.kind(), s)?;
+        self.set(value)
+            .expect("the value was parsed as the registered kind");
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for Entry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Entry")
+            .field("name", &self.name)
+            .field("value", &self.get())
+            .finish()
+    }
+}
+
+/// A fixed list of named values, usually created with the [`registry!`](crate::registry!) macro.
+#[derive(Clone, Copy, Debug)]
+pub struct Registry {
+    entries: &'static [Entry],
+}
+
+impl Registry {
+    /// Create a new registry.
+    pub const fn new(entries: &'static [Entry]) -> Self {
+        Registry { entries }
+    }
+
+    /// Returns an iterator over all entries.
+    pub fn iter(&self) -> core::slice::Iter<'static, Entry> {
+        self.entries.iter()
+    }
+    /// Returns the entry registered as `name`.
+    pub fn find(&self, name: &str) -> Option<&'static Entry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl IntoIterator for Registry {
+    type Item = &'static Entry;
+    type IntoIter = core::slice::Iter<'static, Entry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Declares a static [`Registry`](crate::registry::Registry) of relaxed statics,
+/// each registered under its own name.
+///
+/// See the [`registry`](crate::registry) module for an example.
+#[macro_export]
+macro_rules! registry {
+    ($(#[$attr:meta])* $vis:vis static $name:ident = [$($value:path),* $(,)?];) => {
+        $(#[$attr])*
+        $vis static $name: $crate::registry::Registry = $crate::registry::Registry::new(&[
+            $($crate::registry::Entry::new(::core::stringify!($value), &$value)),*
+        ]);
+    };
+}
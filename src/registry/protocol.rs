@@ -0,0 +1,443 @@
+//! A tiny framed protocol for reading and writing registered values from a host tool.
+//!
+//! Every frame is a length byte followed by up to 255 payload bytes,
+//! so it can be sent over RTT, a UART or any other byte stream.
+//! The first payload byte is a tag, the rest depends on the message:
+//!
+//! | message                   | payload                                |
+//! |---------------------------|----------------------------------------|
+//! | [`Request::Get`]          | `b'G'`, name                           |
+//! | [`Request::Set`]          | `b'S'`, name length, name, value       |
+//! | [`Request::List`]         | `b'L'`, index as `u16` little endian   |
+//! | [`Response::Value`]       | `b'V'`, value                          |
+//! | [`Response::Entry`]       | `b'N'`, kind, name                     |
+//! | [`Response::Error`]       | `b'E'`, error code                     |
+//!
+//! A value is its kind followed by its little endian bytes,
+//! kinds are numbered in the order of the [`Kind`] variants starting at 0.
+//!
+//! ```
+//! use relaxed::registry::protocol::{FrameReader, Request, Response};
+//! use relaxed::registry::Value;
+//! use relaxed::{registry, RelaxedU16};
+//!
+//! static SPEED: RelaxedU16 = RelaxedU16::new(0);
+//!
+//! registry! {
+//!     static TUNING = [SPEED];
+//! }
+//!
+//! // host side
+//! let mut frame = [0; 32];
+//! let len = Request::Set { name: "SPEED", value: Value::U16(1200) }
+//!     .encode(&mut frame)
+//!     .unwrap();
+//!
+//! // device side
+//! let mut reader = FrameReader::<64>::new();
+//! let mut reply = [0; 32];
+//! let mut reply_len = 0;
+//! for &byte in &frame[..len] {
+//!     if let Some(payload) = reader.push(byte) {
+//!         reply_len = TUNING.handle_frame(payload, &mut reply).unwrap();
+//!     }
+//! }
+//! assert_eq!(SPEED.get(), 1200);
+//!
+//! // host side
+//! assert_eq!(
+//!     Response::decode(&reply[1..reply_len]),
+//!     Ok(Response::Value(Value::U16(1200)))
+//! );
+//! ```
+
+use core::fmt::{Display, Formatter};
+
+use super::{Kind, Registry, Value};
+
+const GET: u8 = b'G';
+const SET: u8 = b'S';
+const LIST: u8 = b'L';
+const VALUE: u8 = b'V';
+const ENTRY: u8 = b'N';
+const ERROR: u8 = b'E';
+
+/// A request sent by the host.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Request<'a> {
+    /// Reads the value registered as `name`.
+    Get {
+        /// The name of the value.
+        name: &'a str,
+    },
+    /// Writes the value registered as `name`.
+    Set {
+        /// The name of the value.
+        name: &'a str,
+        /// The new value, which must have the registered kind.
+        value: Value,
+    },
+    /// Asks for the name and kind of the entry at `index`, to discover the registry.
+    List {
+        /// The index of the entry.
+        index: u16,
+    },
+}
+
+/// A response sent by the device.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Response<'a> {
+    /// The current value, after a get or a set.
+    Value(Value),
+    /// The name and kind of a registry entry.
+    Entry {
+        /// The name of the entry.
+        name: &'a str,
+        /// The kind of the entry.
+        kind: Kind,
+    },
+    /// The request failed.
+    Error(ErrorCode),
+}
+
+/// The reason a request failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ErrorCode {
+    /// The request couldn't be decoded.
+    Malformed = 1,
+    /// No value is registered under the name.
+    UnknownName = 2,
+    /// The value has a different kind than the registered one.
+    KindMismatch = 3,
+    /// The list index is past the end of the registry.
+    OutOfRange = 4,
+}
+
+/// Error returned when a payload is not a valid message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodeError;
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("malformed message")
+    }
+}
+
+/// Error returned when a frame doesn't fit into the buffer or into 255 payload bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferTooSmall;
+
+impl Display for BufferTooSmall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("buffer too small")
+    }
+}
+
+const KINDS: [Kind; 8] = [
+    Kind::Bool,
+    Kind::U8,
+    Kind::U16,
+    Kind::U32,
+    Kind::I8,
+    Kind::I16,
+    Kind::I32,
+    Kind::F32,
+];
+
+fn kind_tag(kind: Kind) -> u8 {
+    KINDS.iter().position(|&k| k == kind).unwrap_or_default() as u8
+}
+
+fn kind_from_tag(tag: u8) -> Result<Kind, DecodeError> {
+    KINDS.get(tag as usize).copied().ok_or(DecodeError)
+}
+
+/// Writes a frame, the payload is built by appending to the writer.
+struct FrameWriter<'b> {
+    buf: &'b mut [u8],
+    len: usize,
+}
+
+impl<'b> FrameWriter<'b> {
+    fn new(buf: &'b mut [u8]) -> Result<Self, BufferTooSmall> {
+        if buf.is_empty() {
+            return Err(BufferTooSmall);
+        }
+        Ok(FrameWriter { buf, len: 1 })
+    }
+
+    fn push(&mut self, bytes: &[u8]) -> Result<(), BufferTooSmall> {
+        let end = self.len + bytes.len();
+        if end > self.buf.len() || end > 256 {
+            return Err(BufferTooSmall);
+        }
+        self.buf[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+
+    fn push_value(&mut self, value: Value) -> Result<(), BufferTooSmall> {
+        self.push(&[kind_tag(value.kind())])?;
+        match value {
+            Value::Bool(v) => self.push(&[v as u8]),
+            Value::U8(v) => self.push(&v.to_le_bytes()),
+            Value::U16(v) => self.push(&v.to_le_bytes()),
+            Value::U32(v) => self.push(&v.to_le_bytes()),
+            Value::I8(v) => self.push(&v.to_le_bytes()),
+            Value::I16(v) => self.push(&v.to_le_bytes()),
+            Value::I32(v) => self.push(&v.to_le_bytes()),
+            Value::F32(v) => self.push(&v.to_le_bytes()),
+        }
+    }
+
+    fn finish(self) -> usize {
+        self.buf[0] = (self.len - 1) as u8;
+        self.len
+    }
+}
+
+/// Reads a payload front to back.
+struct PayloadReader<'a> {
+    payload: &'a [u8],
+}
+
+impl<'a> PayloadReader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        if n > self.payload.len() {
+            return Err(DecodeError);
+        }
+        let (head, tail) = self.payload.split_at(n);
+        self.payload = tail;
+        Ok(head)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        Ok(self.take(N)?.try_into().expect("took N bytes"))
+    }
+
+    fn take_str(&mut self, n: usize) -> Result<&'a str, DecodeError> {
+        core::str::from_utf8(self.take(n)?).map_err(|_| DecodeError)
+    }
+
+    fn take_value(&mut self) -> Result<Value, DecodeError> {
+        let [tag] = self.take_array()?;
+        Ok(match kind_from_tag(tag)? {
+            Kind::Bool => match self.take_array()? {
+                [0] => Value::Bool(false),
+                [1] => Value::Bool(true),
+                _ => return Err(DecodeError),
+            },
+            Kind::U8 => Value::U8(u8::from_le_bytes(self.take_array()?)),
+            Kind::U16 => Value::U16(u16::from_le_bytes(self.take_array()?)),
+            Kind::U32 => Value::U32(u32::from_le_bytes(self.take_array()?)),
+            Kind::I8 => Value::I8(i8::from_le_bytes(self.take_array()?)),
+            Kind::I16 => Value::I16(i16::from_le_bytes(self.take_array()?)),
+            Kind::I32 => Value::I32(i32::from_le_bytes(self.take_array()?)),
+            Kind::F32 => Value::F32(f32::from_le_bytes(self.take_array()?)),
+        })
+    }
+
+    fn rest(&mut self) -> &'a [u8] {
+        core::mem::take(&mut self.payload)
+    }
+
+    fn finish<T>(self, message: T) -> Result<T, DecodeError> {
+        if self.payload.is_empty() {
+            Ok(message)
+        } else {
+            Err(DecodeError)
+        }
+    }
+}
+
+impl<'a> Request<'a> {
+    /// Writes the request as a frame into `buf`, returning the length of the frame.
+    pub fn encode(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let mut writer = FrameWriter::new(buf)?;
+        match *self {
+            Request::Get { name } => {
+                writer.push(&[GET])?;
+                writer.push(name.as_bytes())?;
+            }
+            Request::Set { name, value } => {
+                let name_len = u8::try_from(name.len()).map_err(|_| BufferTooSmall)?;
+                writer.push(&[SET, name_len])?;
+                writer.push(name.as_bytes())?;
+                writer.push_value(value)?;
+            }
+            Request::List { index } => {
+                writer.push(&[LIST])?;
+                writer.push(&index.to_le_bytes())?;
+            }
+        }
+        Ok(writer.finish())
+    }
+
+    /// Decodes a request from the payload of a frame, without the length byte.
+    pub fn decode(payload: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut reader = PayloadReader { payload };
+        let [tag] = reader.take_array()?;
+        let request = match tag {
+            GET => {
+                let name = reader.rest();
+                Request::Get {
+                    name: core::str::from_utf8(name).map_err(|_| DecodeError)?,
+                }
+            }
+            SET => {
+                let [name_len] = reader.take_array()?;
+                let name = reader.take_str(name_len as usize)?;
+                let value = reader.take_value()?;
+                Request::Set { name, value }
+            }
+            LIST => Request::List {
+                index: u16::from_le_bytes(reader.take_array()?),
+            },
+            _ => return Err(DecodeError),
+        };
+        reader.finish(request)
+    }
+}
+
+impl<'a> Response<'a> {
+    /// Writes the response as a frame into `buf`, returning the length of the frame.
+    pub fn encode(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let mut writer = FrameWriter::new(buf)?;
+        match *self {
+            Response::Value(value) => {
+                writer.push(&[VALUE])?;
+                writer.push_value(value)?;
+            }
+            Response::Entry { name, kind } => {
+                writer.push(&[ENTRY, kind_tag(kind)])?;
+                writer.push(name.as_bytes())?;
+            }
+            Response::Error(code) => writer.push(&[ERROR, code as u8])?,
+        }
+        Ok(writer.finish())
+    }
+
+    /// Decodes a response from the payload of a frame, without the length byte.
+    pub fn decode(payload: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut reader = PayloadReader { payload };
+        let [tag] = reader.take_array()?;
+        let response = match tag {
+            VALUE => Response::Value(reader.take_value()?),
+            ENTRY => {
+                let [kind] = reader.take_array()?;
+                let kind = kind_from_tag(kind)?;
+                let name = reader.rest();
+                Response::Entry {
+                    name: core::str::from_utf8(name).map_err(|_| DecodeError)?,
+                    kind,
+                }
+            }
+            ERROR => Response::Error(match reader.take_array()? {
+                [1] => ErrorCode::Malformed,
+                [2] => ErrorCode::UnknownName,
+                [3] => ErrorCode::KindMismatch,
+                [4] => ErrorCode::OutOfRange,
+                _ => return Err(DecodeError),
+            }),
+            _ => return Err(DecodeError),
+        };
+        reader.finish(response)
+    }
+}
+
+impl Registry {
+    /// Performs `request` on the registered values.
+    pub fn handle(&self, request: &Request<'_>) -> Response<'static> {
+        match *request {
+            Request::Get { name } => match self.find(name) {
+                Some(entry) => Response::Value(entry.get()),
+                None => Response::Error(ErrorCode::UnknownName),
+            },
+            Request::Set { name, value } => match self.find(name) {
+                Some(entry) => match entry.set(value) {
+                    Ok(()) => Response::Value(entry.get()),
+                    Err(_) => Response::Error(ErrorCode::KindMismatch),
+                },
+                None => Response::Error(ErrorCode::UnknownName),
+            },
+            Request::List { index } => match self.entries.get(index as usize) {
+                Some(entry) => Response::Entry {
+                    name: entry.name(),
+                    kind: entry.kind(),
+                },
+                None => Response::Error(ErrorCode::OutOfRange),
+            },
+        }
+    }
+
+    /// Decodes the payload of a request frame, performs it,
+    /// and writes the response frame into `buf`, returning its length.
+    pub fn handle_frame(&self, payload: &[u8], buf: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let response = match Request::decode(payload) {
+            Ok(request) => self.handle(&request),
+            Err(DecodeError) => Response::Error(ErrorCode::Malformed),
+        };
+        response.encode(buf)
+    }
+}
+
+/// Splits a byte stream into frames.
+///
+/// Frames with a payload longer than `N` are skipped.
+#[derive(Debug)]
+pub struct FrameReader<const N: usize> {
+    buf: [u8; N],
+    expected: Option<usize>,
+    len: usize,
+}
+
+impl<const N: usize> FrameReader<N> {
+    /// Create a new reader waiting for the length byte of a frame.
+    pub const fn new() -> Self {
+        FrameReader {
+            buf: [0; N],
+            expected: None,
+            len: 0,
+        }
+    }
+
+    /// Feeds the next byte of the stream, returning the payload once a frame is complete.
+    pub fn push(&mut self, byte: u8) -> Option<&[u8]> {
+        let Some(expected) = self.expected else {
+            self.expected = Some(byte as usize);
+            self.len = 0;
+            return if byte == 0 { self.complete() } else { None };
+        };
+        if self.len < N {
+            self.buf[self.len] = byte;
+        }
+        self.len += 1;
+        if self.len == expected {
+            self.complete()
+        } else {
+            None
+        }
+    }
+
+    /// Drops a partially received frame, e.g. after a timeout.
+    pub fn reset(&mut self) {
+        self.expected = None;
+        self.len = 0;
+    }
+
+    fn complete(&mut self) -> Option<&[u8]> {
+        self.expected = None;
+        if self.len <= N {
+            Some(&self.buf[..self.len])
+        } else {
+            None
+        }
+    }
+}
+
+impl<const N: usize> Default for FrameReader<N> {
+    fn default() -> Self {
+        FrameReader::new()
+    }
+}
@@ -0,0 +1,62 @@
+use crate::RelaxedRgb;
+
+/// Packs the channels as `0x00RRGGBB`.
+pub(crate) const fn pack((r, g, b): (u8, u8, u8)) -> u32 {
+    (r as u32) << 16 | (g as u32) << 8 | b as u32
+}
+
+pub(crate) const fn unpack(bits: u32) -> (u8, u8, u8) {
+    ((bits >> 16) as u8, (bits >> 8) as u8, bits as u8)
+}
+
+impl RelaxedRgb {
+    /// Returns the red channel.
+    #[inline(always)]
+    pub fn red(&self) -> u8 {
+        self.get().0
+    }
+    /// Returns the green channel.
+    #[inline(always)]
+    pub fn green(&self) -> u8 {
+        self.get().1
+    }
+    /// Returns the blue channel.
+    #[inline(always)]
+    pub fn blue(&self) -> u8 {
+        self.get().2
+    }
+    /// Replaces the red channel, leaving the others untouched.
+    #[inline(always)]
+    pub fn set_red(&self, val: u8) {
+        self.set_channel(16, val)
+    }
+    /// Replaces the green channel, leaving the others untouched.
+    #[inline(always)]
+    pub fn set_green(&self, val: u8) {
+        self.set_channel(8, val)
+    }
+    /// Replaces the blue channel, leaving the others untouched.
+    #[inline(always)]
+    pub fn set_blue(&self, val: u8) {
+        self.set_channel(0, val)
+    }
+    /// Returns the color as RGB565, dropping the low bits of each channel.
+    #[inline(always)]
+    pub fn get_rgb565(&self) -> u16 {
+        let (r, g, b) = self.get();
+        (r as u16 >> 3) << 11 | (g as u16 >> 2) << 5 | b as u16 >> 3
+    }
+    /// Stores a RGB565 color, replicating the high bits of each channel into the low bits.
+    #[inline(always)]
+    pub fn set_rgb565(&self, val: u16) {
+        let r = (val >> 11) as u8 & 0x1f;
+        let g = (val >> 5) as u8 & 0x3f;
+        let b = val as u8 & 0x1f;
+        self.set((r << 3 | r >> 2, g << 2 | g >> 4, b << 3 | b >> 2))
+    }
+
+    fn set_channel(&self, shift: u32, val: u8) {
+        let mask = !(0xff << shift);
+        self.get_and_update(|rgb| unpack(pack(rgb) & mask | (val as u32) << shift));
+    }
+}
@@ -0,0 +1,214 @@
+use core::cell::UnsafeCell;
+use core::fmt::{Debug, Formatter};
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// A single-producer single-consumer queue of up to `N` `Copy` values,
+/// e.g. to pass received bytes from an interrupt handler to the main loop.
+///
+/// The ring is split once into a [`Producer`] and a [`Consumer`] handle,
+/// which can be moved to the two contexts.
+/// Unlike the other wrappers the indices use acquire/release ordering,
+/// so the consumer always sees the values written by the producer.
+///
+/// ```
+/// use relaxed::RelaxedRingBuffer;
+///
+/// static RX: RelaxedRingBuffer<u8, 4> = RelaxedRingBuffer::new();
+///
+/// let (mut producer, mut consumer) = RX.try_split().unwrap();
+/// assert!(RX.try_split().is_none());
+///
+/// // in the UART interrupt:
+/// for byte in *b"hello" {
+///     if producer.push(byte).is_err() {
+///         break; // overrun
+///     }
+/// }
+///
+/// // in the main loop:
+/// assert_eq!(consumer.len(), 4);
+/// assert_eq!(consumer.pop(), Some(b'h'));
+/// assert!(producer.push(b'!').is_ok());
+/// let rest: Vec<u8> = core::iter::from_fn(|| consumer.pop()).collect();
+/// assert_eq!(rest, b"ell!");
+/// ```
+///
+/// The capacity doesn't have to be a power of two:
+///
+/// ```
+/// use relaxed::RelaxedRingBuffer;
+///
+/// let ring = RelaxedRingBuffer::<u32, 3>::new();
+/// let (mut producer, mut consumer) = ring.try_split().unwrap();
+/// for i in 0..100 {
+///     producer.push(i).unwrap();
+///     producer.push(i + 1000).unwrap();
+///     assert_eq!(consumer.pop(), Some(i));
+///     assert_eq!(consumer.pop(), Some(i + 1000));
+/// }
+/// assert!(consumer.is_empty());
+/// ```
+pub struct RelaxedRingBuffer<T, const N: usize> {
+    buf: [UnsafeCell<MaybeUninit<T>>; N],
+    /// Index of the next value to pop, in `0..2 * N`.
+    head: AtomicUsize,
+    /// Index of the next value to push, in `0..2 * N`.
+    tail: AtomicUsize,
+    split: AtomicBool,
+}
+
+// SAFETY: the producer only writes slots the consumer doesn't read and vice versa,
+// and a slot changes hands with a release store and an acquire load of an index
+unsafe impl<T: Send, const N: usize> Sync for RelaxedRingBuffer<T, N> {}
+
+impl<T: Copy, const N: usize> RelaxedRingBuffer<T, N> {
+    /// Create a new empty ring.
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                N > 0 && N <= usize::MAX / 2,
+                "N must be in 1..=usize::MAX / 2"
+            )
+        };
+        RelaxedRingBuffer {
+            buf: [const { UnsafeCell::new(MaybeUninit::uninit()) }; N],
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            split: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the producer and consumer handles,
+    /// or `None` if the ring was split before.
+    pub fn try_split(&self) -> Option<(Producer<'_, T, N>, Consumer<'_, T, N>)> {
+        if self.split.swap(true, Ordering::Relaxed) {
+            None
+        } else {
+            Some((Producer { ring: self }, Consumer { ring: self }))
+        }
+    }
+    /// Returns the maximum number of queued values.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+    /// Returns the number of queued values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+        // values popped after loading `head` may have made room for more pushes
+        distance::<N>(head, tail).min(N)
+    }
+    /// Returns `true` if no values are queued.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: Copy, const N: usize> Default for RelaxedRingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Debug for RelaxedRingBuffer<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Relaxed);
+        f.debug_struct("RelaxedRingBuffer")
+            .field("len", &distance::<N>(head, tail))
+            .field("capacity", &N)
+            .finish()
+    }
+}
+
+// The indices run over `0..2 * N` instead of wrapping at `usize::MAX`,
+// so consecutive indices map to consecutive slots for any `N`,
+// and a full ring (`N` apart) is still distinguishable from an empty one.
+
+/// Returns the number of values in `head..tail`.
+#[inline(always)]
+const fn distance<const N: usize>(head: usize, tail: usize) -> usize {
+    if tail >= head {
+        tail - head
+    } else {
+        tail + 2 * N - head
+    }
+}
+
+/// Returns the index after `index`.
+#[inline(always)]
+const fn next<const N: usize>(index: usize) -> usize {
+    if index + 1 == 2 * N {
+        0
+    } else {
+        index + 1
+    }
+}
+
+/// The pushing half of a [`RelaxedRingBuffer`].
+pub struct Producer<'a, T, const N: usize> {
+    ring: &'a RelaxedRingBuffer<T, N>,
+}
+
+impl<T: Copy, const N: usize> Producer<'_, T, N> {
+    /// Appends a value, or returns it back if the ring is full.
+    pub fn push(&mut self, val: T) -> Result<(), T> {
+        let tail = self.ring.tail.load(Ordering::Relaxed);
+        if distance::<N>(self.ring.head.load(Ordering::Acquire), tail) == N {
+            return Err(val);
+        }
+        // SAFETY: the slot is outside of `head..tail`, so the consumer doesn't read it
+        unsafe { (*self.ring.buf[tail % N].get()).write(val) };
+        self.ring.tail.store(next::<N>(tail), Ordering::Release);
+        Ok(())
+    }
+    /// Returns the number of queued values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ring.len()
+    }
+    /// Returns `true` if no values are queued.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+    /// Returns `true` if no more values fit.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.ring.len() == N
+    }
+}
+
+/// The popping half of a [`RelaxedRingBuffer`].
+pub struct Consumer<'a, T, const N: usize> {
+    ring: &'a RelaxedRingBuffer<T, N>,
+}
+
+impl<T: Copy, const N: usize> Consumer<'_, T, N> {
+    /// Removes the oldest value, or returns `None` if the ring is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let head = self.ring.head.load(Ordering::Relaxed);
+        if self.ring.tail.load(Ordering::Acquire) == head {
+            return None;
+        }
+        // SAFETY: the slot is inside of `head..tail`, so the producer initialized it
+        // and doesn't write it until `head` moves past it
+        let val = unsafe { (*self.ring.buf[head % N].get()).assume_init() };
+        self.ring.head.store(next::<N>(head), Ordering::Release);
+        Some(val)
+    }
+    /// Returns the number of queued values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ring.len()
+    }
+    /// Returns `true` if no values are queued.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+}
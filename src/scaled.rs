@@ -0,0 +1,113 @@
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicI32, Ordering};
+
+use crate::RelaxedValue;
+
+/// A decimal value stored as an `i32` count of `1 / SCALE` units,
+/// that always uses `Ordering::Relaxed` for access.
+///
+/// The raw value is used for storage and the atomic operations,
+/// `f32` only appears at the API boundary for targets that can afford it.
+///
+/// ```
+/// use relaxed::RelaxedScaled;
+///
+/// // millivolts
+/// static VBAT: RelaxedScaled<1000> = RelaxedScaled::new(0);
+///
+/// VBAT.set(3300);
+/// assert_eq!(VBAT.get_f32(), 3.3);
+///
+/// VBAT.set_f32(3.7);
+/// assert_eq!(VBAT.get(), 3700);
+/// ```
+#[repr(transparent)]
+pub struct RelaxedScaled<const SCALE: u32>(AtomicI32);
+
+impl<const SCALE: u32> RelaxedScaled<SCALE> {
+    /// Create a new value from the raw scaled count.
+    #[inline(always)]
+    pub const fn new(raw: i32) -> Self {
+        const { assert!(SCALE > 0, "SCALE must be non-zero") };
+        RelaxedScaled(AtomicI32::new(raw))
+    }
+
+    /// Performs an atomic load with relaxed ordering, returning the raw scaled count.
+    #[inline(always)]
+    pub fn get(&self) -> i32 {
+        self.0.load(Ordering::Relaxed)
+    }
+    /// Performs an atomic store of the raw scaled count with relaxed ordering.
+    #[inline(always)]
+    pub fn set(&self, raw: i32) {
+        self.0.store(raw, Ordering::Relaxed)
+    }
+    /// Stores a raw value, returning the previous raw value.
+    #[inline(always)]
+    pub fn swap(&self, raw: i32) -> i32 {
+        self.0.swap(raw, Ordering::Relaxed)
+    }
+    /// Stores `new` if the current raw value is `current`.
+    /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+    #[inline(always)]
+    pub fn compare_exchange(&self, current: i32, new: i32) -> Result<i32, i32> {
+        self.0
+            .compare_exchange(current, new, Ordering::Relaxed, Ordering::Relaxed)
+    }
+    /// Returns the value in whole units.
+    #[inline(always)]
+    pub fn get_f32(&self) -> f32 {
+        self.get() as f32 / SCALE as f32
+    }
+    /// Stores a value given in whole units, rounded to the nearest `1 / SCALE`
+    /// and saturated to the `i32` range.
+    #[inline(always)]
+    pub fn set_f32(&self, val: f32) {
+        let scaled = val * SCALE as f32;
+        let rounded = if scaled < 0.0 {
+            scaled - 0.5
+        } else {
+            scaled + 0.5
+        };
+        self.set(rounded as i32)
+    }
+}
+
+impl<const SCALE: u32> RelaxedValue for RelaxedScaled<SCALE> {
+    type Value = i32;
+
+    #[inline(always)]
+    fn get(&self) -> i32 {
+        RelaxedScaled::get(self)
+    }
+    #[inline(always)]
+    fn set(&self, raw: i32) {
+        RelaxedScaled::set(self, raw)
+    }
+    #[inline(always)]
+    fn swap(&self, raw: i32) -> i32 {
+        RelaxedScaled::swap(self, raw)
+    }
+    #[inline(always)]
+    fn compare_exchange(&self, current: i32, new: i32) -> Result<i32, i32> {
+        RelaxedScaled::compare_exchange(self, current, new)
+    }
+}
+
+impl<const SCALE: u32> Default for RelaxedScaled<SCALE> {
+    fn default() -> Self {
+        RelaxedScaled::new(0)
+    }
+}
+
+impl<const SCALE: u32> Debug for RelaxedScaled<SCALE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.get_f32(), f)
+    }
+}
+
+impl<const SCALE: u32> From<i32> for RelaxedScaled<SCALE> {
+    fn from(raw: i32) -> Self {
+        RelaxedScaled::new(raw)
+    }
+}
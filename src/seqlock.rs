@@ -0,0 +1,69 @@
+use core::sync::atomic::{fence, AtomicU32, Ordering};
+
+use crate::Backoff;
+
+/// A sequence lock guarding data that is itself stored in relaxed atomics.
+///
+/// Writers are serialized by making the sequence odd for the duration of the write.
+/// Readers never block writers, they retry until they observe the same even sequence
+/// before and after reading, so they never see a mix of two writes.
+pub(crate) struct SeqLock {
+    seq: AtomicU32,
+}
+
+impl SeqLock {
+    pub(crate) const fn new() -> Self {
+        SeqLock {
+            seq: AtomicU32::new(0),
+        }
+    }
+
+    /// Runs `f` with exclusive write access to the guarded data.
+    pub(crate) fn write<R>(&self, f: impl FnOnce() -> R) -> R {
+        let mut backoff = Backoff::new();
+        let mut seq = self.seq.load(Ordering::Relaxed);
+        loop {
+            if seq & 1 == 1 {
+                backoff.snooze();
+                seq = self.seq.load(Ordering::Relaxed);
+                continue;
+            }
+            match self.seq.compare_exchange_weak(
+                seq,
+                seq.wrapping_add(1),
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(actual) => {
+                    backoff.spin();
+                    seq = actual;
+                }
+            }
+        }
+        // keep the data stores from being reordered before the odd sequence
+        fence(Ordering::Release);
+        let result = f();
+        self.seq.store(seq.wrapping_add(2), Ordering::Release);
+        result
+    }
+
+    /// Runs `f` until it has read the guarded data without a concurrent write.
+    pub(crate) fn read<R>(&self, mut f: impl FnMut() -> R) -> R {
+        let mut backoff = Backoff::new();
+        loop {
+            let before = self.seq.load(Ordering::Acquire);
+            if before & 1 == 1 {
+                backoff.snooze();
+                continue;
+            }
+            let result = f();
+            // keep the data loads from being reordered after the second sequence load
+            fence(Ordering::Acquire);
+            if self.seq.load(Ordering::Relaxed) == before {
+                return result;
+            }
+            backoff.spin();
+        }
+    }
+}
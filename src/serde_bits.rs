@@ -0,0 +1,35 @@
+//! Serializes a [`RelaxedF32`] as its `u32` bit pattern instead of a float.
+//!
+//! Use it with `#[serde(with = "relaxed::serde_bits")]` for loss-free persistence,
+//! including NaN payloads and formats that round-trip floats imprecisely.
+//!
+//! ```
+//! use relaxed::RelaxedF32;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Calibration {
+//!     #[serde(with = "relaxed::serde_bits")]
+//!     offset: RelaxedF32,
+//! }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::RelaxedF32;
+
+/// Serializes the bit pattern of the current value.
+pub fn serialize<S>(value: &RelaxedF32, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    value.get().to_bits().serialize(serializer)
+}
+
+/// Deserializes a value from its bit pattern.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<RelaxedF32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    u32::deserialize(deserializer).map(|bits| RelaxedF32::new(f32::from_bits(bits)))
+}
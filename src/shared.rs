@@ -0,0 +1,69 @@
+use alloc::sync::Arc;
+use core::fmt::{Debug, Formatter};
+
+use crate::RelaxedValue;
+
+/// Creates a reader and a writer handle sharing `value`.
+pub fn shared<R: RelaxedValue>(value: R) -> (ReaderHandle<R>, WriterHandle<R>) {
+    let value = Arc::new(value);
+    (ReaderHandle(value.clone()), WriterHandle(value))
+}
+
+/// A handle that can only read a shared relaxed value, see [`shared`].
+pub struct ReaderHandle<R>(Arc<R>);
+
+impl<R: RelaxedValue> ReaderHandle<R> {
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> R::Value {
+        self.0.get()
+    }
+}
+
+impl<R> Clone for ReaderHandle<R> {
+    fn clone(&self) -> Self {
+        ReaderHandle(self.0.clone())
+    }
+}
+
+impl<R: Debug> Debug for ReaderHandle<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A handle that can read and write a shared relaxed value, see [`shared`].
+pub struct WriterHandle<R>(Arc<R>);
+
+impl<R: RelaxedValue> WriterHandle<R> {
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> R::Value {
+        self.0.get()
+    }
+    /// Performs an atomic store with relaxed ordering.
+    #[inline(always)]
+    pub fn set(&self, val: R::Value) {
+        self.0.set(val)
+    }
+    /// Returns the shared value, e.g. to use operations beyond `get` and `set`.
+    pub fn value(&self) -> &R {
+        &self.0
+    }
+    /// Creates another reader handle.
+    pub fn reader(&self) -> ReaderHandle<R> {
+        ReaderHandle(self.0.clone())
+    }
+}
+
+impl<R> Clone for WriterHandle<R> {
+    fn clone(&self) -> Self {
+        WriterHandle(self.0.clone())
+    }
+}
+
+impl<R: Debug> Debug for WriterHandle<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
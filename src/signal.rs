@@ -0,0 +1,61 @@
+use core::ffi::c_int;
+use core::sync::atomic::Ordering;
+use std::io;
+
+use signal_hook_registry::SigId;
+
+use crate::{RelaxedBool, RelaxedU32};
+
+impl RelaxedBool {
+    /// Sets the flag to `true` whenever the process receives `signal`,
+    /// e.g. to request a graceful shutdown on `SIGTERM`.
+    ///
+    /// The handler only performs an atomic store, which is async-signal-safe.
+    /// The store bypasses the `trace` hook, which is not guaranteed to be async-signal-safe.
+    /// The returned id can be passed to `signal_hook_registry::unregister`.
+    ///
+    /// ```
+    /// use relaxed::RelaxedBool;
+    ///
+    /// static SHUTDOWN: RelaxedBool = RelaxedBool::new(false);
+    ///
+    /// const SIGTERM: i32 = 15;
+    /// SHUTDOWN.register_for_signal(SIGTERM).unwrap();
+    ///
+    /// while !SHUTDOWN.get() {
+    ///     // serve requests
+    ///     # break;
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Fails for signals that can't be handled, like `SIGKILL`.
+    pub fn register_for_signal(&'static self, signal: c_int) -> io::Result<SigId> {
+        // SAFETY: the action is async-signal-safe, it only performs an atomic store
+        unsafe {
+            signal_hook_registry::register(signal, move || {
+                self.0.store(true, Ordering::Relaxed);
+            })
+        }
+    }
+}
+
+impl RelaxedU32 {
+    /// Increments the value, wrapping around on overflow, whenever the process receives `signal`,
+    /// e.g. to count `SIGHUP`s as reload requests.
+    ///
+    /// The handler only performs an atomic add, which is async-signal-safe.
+    /// The add bypasses the `trace` hook, which is not guaranteed to be async-signal-safe.
+    /// The returned id can be passed to `signal_hook_registry::unregister`.
+    ///
+    /// # Errors
+    /// Fails for signals that can't be handled, like `SIGKILL`.
+    pub fn count_signal(&'static self, signal: c_int) -> io::Result<SigId> {
+        // SAFETY: the action is async-signal-safe, it only performs an atomic add
+        unsafe {
+            signal_hook_registry::register(signal, move || {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            })
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::RelaxedF32;
+
+/// A setpoint whose output follows the requested target at a limited rate.
+///
+/// Any task may change the target abruptly with [`set_target`](Self::set_target),
+/// while a single control loop calls [`step`](Self::step) to move the output
+/// towards it by at most `rate * dt`.
+///
+/// ```
+/// use relaxed::RelaxedSlewF32;
+///
+/// // at most 10 units per second
+/// static SPEED: RelaxedSlewF32 = RelaxedSlewF32::new(0.0, 10.0);
+///
+/// SPEED.set_target(25.0);
+/// assert_eq!(SPEED.step(1.0), 10.0);
+/// assert_eq!(SPEED.step(1.0), 20.0);
+/// assert_eq!(SPEED.step(1.0), 25.0);
+/// assert_eq!(SPEED.get(), 25.0);
+/// ```
+pub struct RelaxedSlewF32 {
+    target: RelaxedF32,
+    output: RelaxedF32,
+    rate: RelaxedF32,
+}
+
+impl RelaxedSlewF32 {
+    /// Create a new setpoint at `value`, moving at most `rate` units per unit of time.
+    #[inline(always)]
+    pub const fn new(value: f32, rate: f32) -> Self {
+        RelaxedSlewF32 {
+            target: RelaxedF32::new(value),
+            output: RelaxedF32::new(value),
+            rate: RelaxedF32::new(rate),
+        }
+    }
+
+    /// Requests a new target for the output.
+    #[inline(always)]
+    pub fn set_target(&self, value: f32) {
+        self.target.set(value)
+    }
+    /// Returns the requested target.
+    #[inline(always)]
+    pub fn target(&self) -> f32 {
+        self.target.get()
+    }
+    /// Returns the current output.
+    #[inline(always)]
+    pub fn get(&self) -> f32 {
+        self.output.get()
+    }
+    /// Returns the maximum rate of change.
+    #[inline(always)]
+    pub fn rate(&self) -> f32 {
+        self.rate.get()
+    }
+    /// Changes the maximum rate of change.
+    #[inline(always)]
+    pub fn set_rate(&self, rate: f32) {
+        self.rate.set(rate)
+    }
+    /// Moves the output towards the target by at most `rate * dt`, returning the new output.
+    ///
+    /// If `rate * dt` is NaN, e.g. for an infinite rate and a `dt` of zero, the output holds.
+    ///
+    /// ```
+    /// use relaxed::RelaxedSlewF32;
+    ///
+    /// let speed = RelaxedSlewF32::new(0.0, f32::INFINITY);
+    /// speed.set_target(25.0);
+    /// assert_eq!(speed.step(0.0), 0.0);
+    /// assert_eq!(speed.step(f32::NAN), 0.0);
+    /// assert_eq!(speed.step(1.0), 25.0);
+    /// ```
+    pub fn step(&self, dt: f32) -> f32 {
+        let target = self.target.get();
+        let max_delta = (self.rate.get() * dt).abs();
+        // `clamp` panics on NaN bounds
+        let max_delta = if max_delta.is_nan() { 0.0 } else { max_delta };
+        self.output
+            .update_and_get(|output| output + (target - output).clamp(-max_delta, max_delta))
+    }
+    /// Moves the output to the target immediately.
+    pub fn settle(&self) -> f32 {
+        let target = self.target.get();
+        self.output.set(target);
+        target
+    }
+}
+
+impl Debug for RelaxedSlewF32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RelaxedSlewF32")
+            .field("target", &self.target())
+            .field("output", &self.get())
+            .field("rate", &self.rate())
+            .finish()
+    }
+}
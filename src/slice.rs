@@ -0,0 +1,88 @@
+use core::iter::Sum;
+
+use crate::RelaxedValue;
+
+/// Bulk accesses of a slice of relaxed values.
+///
+/// Every element is accessed with its own relaxed load or store,
+/// so the slice as a whole is not read or written atomically.
+///
+/// ```
+/// use relaxed::{RelaxedBool, RelaxedSliceExt, RelaxedU32};
+///
+/// static FAULTS: [RelaxedBool; 4] = [const { RelaxedBool::new(false) }; 4];
+/// static COUNTS: [RelaxedU32; 3] = [const { RelaxedU32::new(0) }; 3];
+///
+/// assert!(!FAULTS.any());
+/// FAULTS[2].set(true);
+/// assert!(FAULTS.any());
+/// assert!(!FAULTS.all());
+///
+/// COUNTS.store_all(&[1, 2, 3]);
+/// assert_eq!(COUNTS.sum(), 6);
+///
+/// let mut snapshot = [0; 3];
+/// COUNTS.load_all(&mut snapshot);
+/// assert_eq!(snapshot, [1, 2, 3]);
+/// ```
+pub trait RelaxedSliceExt<R: RelaxedValue> {
+    /// Loads every element into `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` has a different length.
+    fn load_all(&self, dst: &mut [R::Value]);
+    /// Stores every element of `src`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` has a different length.
+    fn store_all(&self, src: &[R::Value]);
+
+    /// Returns `true` if any element is `true`, stopping at the first one.
+    fn any(&self) -> bool
+    where
+        R: RelaxedValue<Value = bool>;
+    /// Returns `true` if all elements are `true`, stopping at the first `false`.
+    fn all(&self) -> bool
+    where
+        R: RelaxedValue<Value = bool>;
+    /// Returns the sum of all elements.
+    fn sum(&self) -> R::Value
+    where
+        R::Value: Sum;
+}
+
+impl<R: RelaxedValue> RelaxedSliceExt<R> for [R] {
+    fn load_all(&self, dst: &mut [R::Value]) {
+        assert_eq!(self.len(), dst.len(), "slices must have the same length");
+        for (dst, src) in dst.iter_mut().zip(self) {
+            *dst = src.get();
+        }
+    }
+    fn store_all(&self, src: &[R::Value]) {
+        assert_eq!(self.len(), src.len(), "slices must have the same length");
+        for (dst, &src) in self.iter().zip(src) {
+            dst.set(src);
+        }
+    }
+
+    fn any(&self) -> bool
+    where
+        R: RelaxedValue<Value = bool>,
+    {
+        self.iter().any(RelaxedValue::get)
+    }
+    fn all(&self) -> bool
+    where
+        R: RelaxedValue<Value = bool>,
+    {
+        self.iter().all(RelaxedValue::get)
+    }
+    fn sum(&self) -> R::Value
+    where
+        R::Value: Sum,
+    {
+        self.iter().map(RelaxedValue::get).sum()
+    }
+}
@@ -0,0 +1,132 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::RelaxedU32;
+
+/// The timer is stopped.
+const IDLE: u32 = 0;
+/// The timer ran out and wasn't restarted or stopped since.
+const EXPIRED: u32 = u32::MAX;
+
+/// A bank of `N` countdown timers driven by a periodic tick, e.g. from the SysTick interrupt.
+///
+/// Each timer is a single atomic word holding the remaining ticks,
+/// so starting a timer never races with a concurrent [`tick`](Self::tick).
+///
+/// ```
+/// use relaxed::SoftTimers;
+///
+/// static TIMERS: SoftTimers<4> = SoftTimers::new();
+///
+/// // from a task:
+/// TIMERS.start(0, 2);
+///
+/// // from the tick interrupt:
+/// TIMERS.tick();
+/// assert!(!TIMERS.expired(0));
+/// TIMERS.tick();
+///
+/// assert!(TIMERS.take_expired(0));
+/// assert!(!TIMERS.expired(0));
+/// ```
+pub struct SoftTimers<const N: usize> {
+    timers: [RelaxedU32; N],
+}
+
+impl<const N: usize> SoftTimers<N> {
+    /// Create a new bank with every timer stopped.
+    pub const fn new() -> Self {
+        SoftTimers {
+            timers: [const { RelaxedU32::new(IDLE) }; N],
+        }
+    }
+
+    /// Counts down every running timer by one tick.
+    pub fn tick(&self) {
+        for timer in &self.timers {
+            let _ = timer.fetch_update(|remaining| match remaining {
+                IDLE | EXPIRED => None,
+                1 => Some(EXPIRED),
+                remaining => Some(remaining - 1),
+            });
+        }
+    }
+    /// Starts or restarts timer `i` to expire after `ticks` ticks,
+    /// or immediately if `ticks` is zero.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    pub fn start(&self, i: usize, ticks: u32) {
+        let remaining = match ticks {
+            0 => EXPIRED,
+            ticks => ticks.min(EXPIRED - 1),
+        };
+        self.timers[i].set(remaining)
+    }
+    /// Stops timer `i`, also clearing its expiry.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    #[inline(always)]
+    pub fn stop(&self, i: usize) {
+        self.timers[i].set(IDLE)
+    }
+    /// Returns whether timer `i` ran out.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    #[inline(always)]
+    pub fn expired(&self, i: usize) -> bool {
+        self.timers[i].get() == EXPIRED
+    }
+    /// Returns whether timer `i` ran out, and stops it if so.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    #[inline(always)]
+    pub fn take_expired(&self, i: usize) -> bool {
+        self.timers[i].compare_exchange(EXPIRED, IDLE).is_ok()
+    }
+    /// Returns whether timer `i` is counting down.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    #[inline(always)]
+    pub fn is_running(&self, i: usize) -> bool {
+        !matches!(self.timers[i].get(), IDLE | EXPIRED)
+    }
+    /// Returns the ticks left on timer `i`, or `None` if it isn't running.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    pub fn remaining(&self, i: usize) -> Option<u32> {
+        match self.timers[i].get() {
+            IDLE | EXPIRED => None,
+            remaining => Some(remaining),
+        }
+    }
+}
+
+impl<const N: usize> Default for SoftTimers<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Debug for SoftTimers<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries((0..N).map(|i| self.remaining(i)))
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for SoftTimers<N> {
+    /// Serializes the remaining ticks of every timer as a sequence, `None` if it isn't running.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq((0..N).map(|i| self.remaining(i)))
+    }
+}
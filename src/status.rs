@@ -0,0 +1,128 @@
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU16, Ordering};
+
+const OK: u16 = 0;
+const ERR: u16 = 0x100;
+
+/// A `Result<(), E>` that always uses `Ordering::Relaxed` for access,
+/// e.g. the last error reported by an interrupt handler.
+///
+/// `E` is stored as its `u8` code, so `E::try_from(e.into())` must give back `e`.
+///
+/// ```
+/// use relaxed::RelaxedStatus;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum UartError {
+///     Overrun = 1,
+///     Framing = 2,
+/// }
+///
+/// impl From<UartError> for u8 {
+///     fn from(e: UartError) -> u8 {
+///         e as u8
+///     }
+/// }
+///
+/// impl TryFrom<u8> for UartError {
+///     type Error = ();
+///
+///     fn try_from(code: u8) -> Result<Self, ()> {
+///         match code {
+///             1 => Ok(UartError::Overrun),
+///             2 => Ok(UartError::Framing),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// static UART_STATUS: RelaxedStatus<UartError> = RelaxedStatus::ok();
+///
+/// // in the interrupt handler:
+/// UART_STATUS.set_err(UartError::Framing);
+///
+/// // in the main loop:
+/// assert_eq!(UART_STATUS.take_err(), Some(UartError::Framing));
+/// assert_eq!(UART_STATUS.get(), Ok(()));
+/// ```
+#[repr(transparent)]
+pub struct RelaxedStatus<E>(AtomicU16, PhantomData<fn() -> E>);
+
+impl<E> RelaxedStatus<E> {
+    /// Create a new `Ok` status.
+    #[inline(always)]
+    pub const fn ok() -> Self {
+        RelaxedStatus(AtomicU16::new(OK), PhantomData)
+    }
+
+    /// Stores `Ok(())`.
+    #[inline(always)]
+    pub fn set_ok(&self) {
+        self.0.store(OK, Ordering::Relaxed)
+    }
+    /// Returns `true` if the status is `Ok`.
+    #[inline(always)]
+    pub fn is_ok(&self) -> bool {
+        self.0.load(Ordering::Relaxed) == OK
+    }
+    /// Returns `true` if the status is `Err`.
+    #[inline(always)]
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+}
+
+impl<E: Into<u8> + TryFrom<u8>> RelaxedStatus<E> {
+    fn decode(raw: u16) -> Result<(), E> {
+        match raw {
+            OK => Ok(()),
+            _ => match E::try_from(raw as u8) {
+                Ok(e) => Err(e),
+                Err(_) => panic!("error code {} doesn't convert back", raw as u8),
+            },
+        }
+    }
+
+    /// Stores `Err(e)`.
+    #[inline(always)]
+    pub fn set_err(&self, e: E) {
+        self.0.store(ERR | e.into() as u16, Ordering::Relaxed)
+    }
+    /// Stores `val`.
+    #[inline(always)]
+    pub fn set(&self, val: Result<(), E>) {
+        match val {
+            Ok(()) => self.set_ok(),
+            Err(e) => self.set_err(e),
+        }
+    }
+    /// Performs an atomic load with relaxed ordering.
+    ///
+    /// # Panics
+    /// Panics if the stored code doesn't convert back into an `E`.
+    #[inline(always)]
+    pub fn get(&self) -> Result<(), E> {
+        Self::decode(self.0.load(Ordering::Relaxed))
+    }
+    /// Resets the status to `Ok`, returning the error if there was one.
+    ///
+    /// # Panics
+    /// Panics if the stored code doesn't convert back into an `E`.
+    #[inline(always)]
+    pub fn take_err(&self) -> Option<E> {
+        Self::decode(self.0.swap(OK, Ordering::Relaxed)).err()
+    }
+}
+
+impl<E> Default for RelaxedStatus<E> {
+    fn default() -> Self {
+        RelaxedStatus::ok()
+    }
+}
+
+impl<E: Into<u8> + TryFrom<u8> + Debug> Debug for RelaxedStatus<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
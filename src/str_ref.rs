@@ -0,0 +1,107 @@
+use core::fmt::{Debug, Display, Formatter};
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+use crate::seqlock::SeqLock;
+
+/// A `&'static str` that always uses `Ordering::Relaxed` for access.
+///
+/// A string slice is a pointer and a length, which don't fit into a single atomic.
+/// So they are stored in two atomics guarded by a sequence lock,
+/// and [`get`](Self::get) never combines the pointer of one string with the length of another.
+/// Readers retry while a store is in progress,
+/// so don't read or store from an interrupt that can preempt a store.
+///
+/// ```
+/// use relaxed::RelaxedStrRef;
+///
+/// static STATUS: RelaxedStrRef = RelaxedStrRef::new("idle");
+///
+/// STATUS.set("calibrating");
+/// assert_eq!(STATUS.get(), "calibrating");
+///
+/// // strings created at runtime work too
+/// let phase: &'static str = Box::leak(format!("phase {}", 2).into_boxed_str());
+/// assert_eq!(STATUS.swap(phase), "calibrating");
+/// assert_eq!(STATUS.get(), "phase 2");
+/// ```
+pub struct RelaxedStrRef {
+    ptr: AtomicPtr<u8>,
+    len: AtomicUsize,
+    lock: SeqLock,
+}
+
+impl RelaxedStrRef {
+    /// Create a new value.
+    #[inline(always)]
+    pub const fn new(val: &'static str) -> Self {
+        RelaxedStrRef {
+            ptr: AtomicPtr::new(val.as_ptr() as *mut u8),
+            len: AtomicUsize::new(val.len()),
+            lock: SeqLock::new(),
+        }
+    }
+
+    /// Performs an atomic load with relaxed ordering.
+    #[inline]
+    pub fn get(&self) -> &'static str {
+        let (ptr, len) = self.lock.read(|| {
+            (
+                self.ptr.load(Ordering::Relaxed),
+                self.len.load(Ordering::Relaxed),
+            )
+        });
+        // SAFETY: the lock guarantees that the pointer and length were stored together,
+        // so they always come from the same `&'static str`
+        unsafe { from_raw_parts(ptr, len) }
+    }
+    /// Performs an atomic store with relaxed ordering.
+    #[inline]
+    pub fn set(&self, val: &'static str) {
+        self.swap(val);
+    }
+    /// Stores a value, returning the previous value.
+    #[inline]
+    pub fn swap(&self, val: &'static str) -> &'static str {
+        let (ptr, len) = self.lock.write(|| {
+            (
+                self.ptr.swap(val.as_ptr() as *mut u8, Ordering::Relaxed),
+                self.len.swap(val.len(), Ordering::Relaxed),
+            )
+        });
+        // SAFETY: writers are serialized by the lock,
+        // so the pointer and length always come from the same `&'static str`
+        unsafe { from_raw_parts(ptr, len) }
+    }
+}
+
+/// # Safety
+/// `ptr` and `len` must come from the same `&'static str`.
+#[inline(always)]
+unsafe fn from_raw_parts(ptr: *const u8, len: usize) -> &'static str {
+    // SAFETY: guaranteed by the caller
+    unsafe { core::str::from_utf8_unchecked(core::slice::from_raw_parts(ptr, len)) }
+}
+
+impl Default for RelaxedStrRef {
+    fn default() -> Self {
+        RelaxedStrRef::new("")
+    }
+}
+
+impl Debug for RelaxedStrRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.get(), f)
+    }
+}
+
+impl Display for RelaxedStrRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.get(), f)
+    }
+}
+
+impl From<&'static str> for RelaxedStrRef {
+    fn from(val: &'static str) -> Self {
+        RelaxedStrRef::new(val)
+    }
+}
@@ -0,0 +1,90 @@
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+use crate::seqlock::SeqLock;
+
+/// A string of up to `N` bytes that can be shared between threads without tearing.
+///
+/// Unlike the other wrappers, a write touches many atomics,
+/// so they are guarded by a sequence lock.
+/// Readers copy the contents out and retry if a write happened in the meantime.
+///
+/// ```
+/// use relaxed::RelaxedString;
+///
+/// static STATUS: RelaxedString<8> = RelaxedString::new();
+///
+/// STATUS.set("calibrating");
+///
+/// let mut buf = [0; 8];
+/// assert_eq!(STATUS.read(&mut buf), "calibrat");
+/// ```
+pub struct RelaxedString<const N: usize> {
+    lock: SeqLock,
+    len: AtomicUsize,
+    bytes: [AtomicU8; N],
+}
+
+impl<const N: usize> RelaxedString<N> {
+    /// Create a new empty string.
+    pub const fn new() -> Self {
+        RelaxedString {
+            lock: SeqLock::new(),
+            len: AtomicUsize::new(0),
+            bytes: [const { AtomicU8::new(0) }; N],
+        }
+    }
+
+    /// Returns the maximum length in bytes.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Stores `val`, truncated to the last char boundary that fits into `N` bytes.
+    pub fn set(&self, val: &str) {
+        let mut len = val.len().min(N);
+        while !val.is_char_boundary(len) {
+            len -= 1;
+        }
+        self.lock.write(|| {
+            for (cell, &byte) in self.bytes.iter().zip(&val.as_bytes()[..len]) {
+                cell.store(byte, Ordering::Relaxed);
+            }
+            self.len.store(len, Ordering::Relaxed);
+        });
+    }
+
+    /// Copies the current contents into `buf` and returns them as a string slice.
+    pub fn read<'b>(&self, buf: &'b mut [u8; N]) -> &'b str {
+        let len = self.lock.read(|| {
+            for (byte, cell) in buf.iter_mut().zip(&self.bytes) {
+                *byte = cell.load(Ordering::Relaxed);
+            }
+            self.len.load(Ordering::Relaxed).min(N)
+        });
+        core::str::from_utf8(&buf[..len]).expect("only whole strings are ever stored")
+    }
+}
+
+impl<const N: usize> Default for RelaxedString<N> {
+    fn default() -> Self {
+        RelaxedString::new()
+    }
+}
+
+impl<const N: usize> Debug for RelaxedString<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut buf = [0; N];
+        Debug::fmt(self.read(&mut buf), f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for RelaxedString<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.read(&mut [0; N]).serialize(serializer)
+    }
+}
@@ -0,0 +1,121 @@
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// A pointer and a small tag packed into a single atomic, that always uses
+/// `Ordering::Relaxed` for access.
+///
+/// The tag lives in the low bits of the pointer that are always zero due to the alignment of `T`,
+/// so it can hold values up to [`TAG_MASK`](Self::TAG_MASK),
+/// e.g. `0..=3` for `u32` or `0..=7` for `u64` on most targets.
+/// Comparing the pair in one compare and exchange is the building block for
+/// versioned handles and simple lock-free data structures.
+///
+/// Relaxed ordering doesn't make the pointee visible to other threads,
+/// publish it with a separate release/acquire pair if needed.
+///
+/// ```
+/// use relaxed::RelaxedTaggedPtr;
+///
+/// let mut slots = [0u32; 2];
+/// let [first, second] = slots.each_mut().map(|slot| slot as *mut u32);
+///
+/// let head = RelaxedTaggedPtr::new(first, 0);
+/// assert_eq!(head.compare_exchange((first, 0), (second, 1)), Ok((first, 0)));
+/// assert_eq!(head.get(), (second, 1));
+/// ```
+#[repr(transparent)]
+pub struct RelaxedTaggedPtr<T>(AtomicPtr<T>);
+
+impl<T> RelaxedTaggedPtr<T> {
+    /// The largest tag that fits into the alignment bits of `T`.
+    pub const TAG_MASK: usize = core::mem::align_of::<T>() - 1;
+
+    fn pack((ptr, tag): (*mut T, usize)) -> *mut T {
+        assert!(
+            tag <= Self::TAG_MASK,
+            "tag {tag} doesn't fit into the alignment bits"
+        );
+        debug_assert!(ptr.addr() & Self::TAG_MASK == 0, "pointer is not aligned");
+        ptr.map_addr(|addr| addr | tag)
+    }
+
+    fn unpack(raw: *mut T) -> (*mut T, usize) {
+        (
+            raw.map_addr(|addr| addr & !Self::TAG_MASK),
+            raw.addr() & Self::TAG_MASK,
+        )
+    }
+
+    /// Create a new null pointer with a tag of zero.
+    #[inline(always)]
+    pub const fn null() -> Self {
+        RelaxedTaggedPtr(AtomicPtr::new(core::ptr::null_mut()))
+    }
+    /// Create a new value.
+    ///
+    /// # Panics
+    /// Panics if `tag` is greater than [`TAG_MASK`](Self::TAG_MASK).
+    #[inline(always)]
+    pub fn new(ptr: *mut T, tag: usize) -> Self {
+        RelaxedTaggedPtr(AtomicPtr::new(Self::pack((ptr, tag))))
+    }
+
+    /// Performs an atomic load with relaxed ordering, returning the pointer and the tag.
+    #[inline(always)]
+    pub fn get(&self) -> (*mut T, usize) {
+        Self::unpack(self.0.load(Ordering::Relaxed))
+    }
+    /// Performs an atomic store with relaxed ordering.
+    ///
+    /// # Panics
+    /// Panics if `tag` is greater than [`TAG_MASK`](Self::TAG_MASK).
+    #[inline(always)]
+    pub fn set(&self, ptr: *mut T, tag: usize) {
+        self.0.store(Self::pack((ptr, tag)), Ordering::Relaxed)
+    }
+    /// Stores a value, returning the previous value.
+    ///
+    /// # Panics
+    /// Panics if `tag` is greater than [`TAG_MASK`](Self::TAG_MASK).
+    #[inline(always)]
+    pub fn swap(&self, ptr: *mut T, tag: usize) -> (*mut T, usize) {
+        Self::unpack(self.0.swap(Self::pack((ptr, tag)), Ordering::Relaxed))
+    }
+    /// Stores `new` if the current pointer and tag are both equal to `current`.
+    /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+    ///
+    /// # Panics
+    /// Panics if a tag is greater than [`TAG_MASK`](Self::TAG_MASK).
+    #[inline(always)]
+    pub fn compare_exchange(
+        &self,
+        current: (*mut T, usize),
+        new: (*mut T, usize),
+    ) -> Result<(*mut T, usize), (*mut T, usize)> {
+        self.0
+            .compare_exchange(
+                Self::pack(current),
+                Self::pack(new),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
+            .map(Self::unpack)
+            .map_err(Self::unpack)
+    }
+}
+
+impl<T> Default for RelaxedTaggedPtr<T> {
+    fn default() -> Self {
+        RelaxedTaggedPtr::null()
+    }
+}
+
+impl<T> Debug for RelaxedTaggedPtr<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (ptr, tag) = self.get();
+        f.debug_struct("RelaxedTaggedPtr")
+            .field("ptr", &ptr)
+            .field("tag", &tag)
+            .finish()
+    }
+}
@@ -0,0 +1,143 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::RelaxedValue;
+
+/// Triple modular redundancy: three copies of a relaxed value, read by majority vote.
+///
+/// [`set`](Self::set) writes all three copies, [`get_and_repair`](Self::get_and_repair)
+/// votes and overwrites a single disagreeing copy, reporting which one it was.
+///
+/// ```
+/// use relaxed::{RelaxedTmr, RelaxedU32, Vote};
+///
+/// static LIMIT: RelaxedTmr<RelaxedU32> = RelaxedTmr::new([const { RelaxedU32::new(100) }; 3]);
+///
+/// LIMIT.set(200);
+/// assert_eq!(LIMIT.get(), Some(200));
+///
+/// // a bit flip in one of the copies
+/// LIMIT.copies()[1].set(201);
+/// assert_eq!(LIMIT.get_and_repair(), Vote::Majority { value: 200, faulty: 1 });
+/// assert_eq!(LIMIT.vote(), Vote::Unanimous(200));
+/// ```
+pub struct RelaxedTmr<R> {
+    copies: [R; 3],
+}
+
+/// The outcome of a majority vote over the copies of a [`RelaxedTmr`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Vote<T> {
+    /// All three copies agreed.
+    Unanimous(T),
+    /// Two copies agreed on `value`, the copy at index `faulty` disagreed.
+    Majority {
+        /// The value of the agreeing copies.
+        value: T,
+        /// The index of the disagreeing copy.
+        faulty: usize,
+    },
+    /// All three copies were different.
+    NoMajority([T; 3]),
+}
+
+impl<T> Vote<T> {
+    /// Returns the majority value, or `None` if all three copies were different.
+    pub fn value(self) -> Option<T> {
+        match self {
+            Vote::Unanimous(value) | Vote::Majority { value, .. } => Some(value),
+            Vote::NoMajority(_) => None,
+        }
+    }
+    /// Returns `true` if any copy disagreed.
+    pub fn is_fault(&self) -> bool {
+        !matches!(self, Vote::Unanimous(_))
+    }
+}
+
+impl<R: RelaxedValue> RelaxedTmr<R>
+where
+    R::Value: PartialEq,
+{
+    /// Create a new value from three copies, which should hold the same value.
+    #[inline(always)]
+    pub const fn new(copies: [R; 3]) -> Self {
+        RelaxedTmr { copies }
+    }
+
+    /// Returns the individual copies, e.g. to scrub them or inject faults in tests.
+    #[inline(always)]
+    pub fn copies(&self) -> &[R; 3] {
+        &self.copies
+    }
+    /// Stores `val` in all three copies.
+    pub fn set(&self, val: R::Value) {
+        for copy in &self.copies {
+            copy.set(val);
+        }
+    }
+    /// Loads all three copies and votes on them.
+    pub fn vote(&self) -> Vote<R::Value> {
+        Self::vote_on(self.load())
+    }
+    fn load(&self) -> [R::Value; 3] {
+        [0, 1, 2].map(|i| self.copies[i].get())
+    }
+    fn vote_on([a, b, c]: [R::Value; 3]) -> Vote<R::Value> {
+        match (a == b, b == c, a == c) {
+            (true, true, _) => Vote::Unanimous(a),
+            (true, false, _) => Vote::Majority {
+                value: a,
+                faulty: 2,
+            },
+            (false, true, _) => Vote::Majority {
+                value: b,
+                faulty: 0,
+            },
+            (false, false, true) => Vote::Majority {
+                value: a,
+                faulty: 1,
+            },
+            (false, false, false) => Vote::NoMajority([a, b, c]),
+        }
+    }
+    /// Returns the majority value, or `None` if all three copies are different.
+    #[inline(always)]
+    pub fn get(&self) -> Option<R::Value> {
+        self.vote().value()
+    }
+    /// Votes like [`vote`](Self::vote), and overwrites a disagreeing copy with the majority value.
+    ///
+    /// The copy is only repaired if it still holds the disagreeing value,
+    /// so a concurrent [`set`](Self::set) isn't undone.
+    pub fn get_and_repair(&self) -> Vote<R::Value> {
+        let values = self.load();
+        let vote = Self::vote_on(values);
+        if let Vote::Majority { value, faulty } = vote {
+            let _ = self.copies[faulty].compare_exchange(values[faulty], value);
+        }
+        vote
+    }
+}
+
+impl<R: RelaxedValue> Debug for RelaxedTmr<R>
+where
+    R::Value: PartialEq + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.vote().fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R: RelaxedValue> serde::Serialize for RelaxedTmr<R>
+where
+    R::Value: PartialEq + serde::Serialize,
+{
+    /// Serializes the majority value, or `None` if all three copies are different.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
@@ -0,0 +1,98 @@
+//! A global hook observing every store to the relaxed wrappers, for debugging data flow.
+//!
+//! Once a hook is installed, every store and read-modify-write on the primitive
+//! wrappers like [`RelaxedU32`](crate::RelaxedU32) calls it with the name of the type,
+//! the address of the value and the raw bits before and after the store,
+//! e.g. to mirror them to an RTT channel.
+//!
+//! Plain [`set`](crate::RelaxedU32::set)s are performed as swaps while this feature is enabled,
+//! so the previous value can be reported. The hook must not store to relaxed wrappers itself,
+//! as that would call it recursively. Stores from the signal handlers of
+//! `RelaxedBool::register_for_signal` and `RelaxedU32::count_signal` are never reported,
+//! as the hook is not guaranteed to be async-signal-safe.
+//! Neither are accesses through the atomic returned by `as_atomic`, which bypass the wrapper.
+//!
+//! ```
+//! use relaxed::{trace, RelaxedU32};
+//!
+//! static MODE: RelaxedU32 = RelaxedU32::new(1);
+//!
+//! fn log_store(name: &'static str, addr: *const (), old_bits: u64, new_bits: u64) {
+//!     if addr == (&MODE as *const RelaxedU32).cast() {
+//!         println!("{name} changed from {old_bits} to {new_bits}");
+//!     }
+//! }
+//!
+//! trace::set_hook(Some(log_store));
+//! MODE.set(2);
+//! trace::set_hook(None);
+//! ```
+
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// The signature of a trace hook: type name, address, old bits and new bits.
+///
+/// Values wider than 64 bits are truncated, signed values are zero extended.
+pub type TraceHook = fn(name: &'static str, addr: *const (), old_bits: u64, new_bits: u64);
+
+static HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Installs `hook`, or removes the current hook if `None`.
+pub fn set_hook(hook: Option<TraceHook>) {
+    let ptr = hook.map_or(ptr::null_mut(), |hook| hook as *mut ());
+    HOOK.store(ptr, Ordering::Release)
+}
+
+/// Returns the installed hook.
+pub fn hook() -> Option<TraceHook> {
+    let ptr = HOOK.load(Ordering::Acquire);
+    // SAFETY: non-null pointers always come from a `TraceHook`
+    (!ptr.is_null()).then(|| unsafe { core::mem::transmute::<*mut (), TraceHook>(ptr) })
+}
+
+#[inline]
+pub(crate) fn emit<B: Bits>(name: &'static str, addr: *const (), old: B, new: B) {
+    if let Some(hook) = hook() {
+        hook(name, addr, old.bits(), new.bits())
+    }
+}
+
+/// The raw values stored by the atomics behind the wrappers.
+pub(crate) trait Bits: Copy {
+    fn bits(self) -> u64;
+}
+
+macro_rules! bits_impls {
+    ($($ty:ty => $unsigned:ty),*) => {
+        $(
+            impl Bits for $ty {
+                #[inline(always)]
+                fn bits(self) -> u64 {
+                    self as $unsigned as u64
+                }
+            }
+        )*
+    };
+}
+
+bits_impls!(
+    bool => u8,
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128
+);
+
+impl Bits for f32 {
+    #[inline(always)]
+    fn bits(self) -> u64 {
+        self.to_bits() as u64
+    }
+}
@@ -0,0 +1,60 @@
+use critical_section::CriticalSection;
+
+use crate::RelaxedValue;
+
+/// Runs `f` inside a critical section, so the relaxed values it accesses through
+/// [`get_in_tx`](TransactionExt::get_in_tx) and [`set_in_tx`](TransactionExt::set_in_tx)
+/// are updated as a group, without interleaving from interrupts or other transactions.
+///
+/// Accesses outside of transactions are not blocked,
+/// so every writer of a group of values should use a transaction.
+///
+/// ```
+/// use relaxed::{transaction, RelaxedF32, RelaxedU32, TransactionExt};
+///
+/// static SAMPLES: RelaxedU32 = RelaxedU32::new(0);
+/// static SUM: RelaxedF32 = RelaxedF32::new(0.0);
+///
+/// transaction(|tx| {
+///     SAMPLES.set_in_tx(tx, SAMPLES.get_in_tx(tx) + 1);
+///     SUM.set_in_tx(tx, SUM.get_in_tx(tx) + 2.5);
+/// });
+///
+/// let mean = transaction(|tx| SUM.get_in_tx(tx) / SAMPLES.get_in_tx(tx) as f32);
+/// assert_eq!(mean, 2.5);
+/// ```
+#[inline]
+pub fn transaction<R>(f: impl FnOnce(&Transaction<'_>) -> R) -> R {
+    critical_section::with(|cs| f(&Transaction { cs }))
+}
+
+/// A token proving that the code runs inside a [`transaction`].
+#[derive(Clone, Copy, Debug)]
+pub struct Transaction<'cs> {
+    cs: CriticalSection<'cs>,
+}
+
+impl<'cs> Transaction<'cs> {
+    /// Returns the critical section of the transaction,
+    /// e.g. to also borrow a `critical_section::Mutex`.
+    #[inline(always)]
+    pub fn critical_section(&self) -> CriticalSection<'cs> {
+        self.cs
+    }
+}
+
+/// Accesses of relaxed values that require a [`Transaction`] token.
+pub trait TransactionExt: RelaxedValue {
+    /// Performs an atomic load with relaxed ordering inside a transaction.
+    #[inline(always)]
+    fn get_in_tx(&self, _tx: &Transaction<'_>) -> Self::Value {
+        self.get()
+    }
+    /// Performs an atomic store with relaxed ordering inside a transaction.
+    #[inline(always)]
+    fn set_in_tx(&self, _tx: &Transaction<'_>, val: Self::Value) {
+        self.set(val)
+    }
+}
+
+impl<R: RelaxedValue + ?Sized> TransactionExt for R {}
@@ -0,0 +1,184 @@
+//! A relaxed cell for [`uom`] quantities, storing the quantity's `f32` or `u32` value
+//! in base units, so shared physical values keep their units at the API boundary.
+//!
+//! ```
+//! use relaxed::uom::RelaxedQuantity;
+//! use uom::si::f32::ThermodynamicTemperature;
+//! use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+//!
+//! type RelaxedTemperature =
+//!     RelaxedQuantity<uom::si::thermodynamic_temperature::Dimension, uom::si::SI<f32>, f32>;
+//!
+//! static BOARD_TEMP: RelaxedTemperature = RelaxedTemperature::from_base(0.0);
+//!
+//! // in the sensor task:
+//! BOARD_TEMP.set(ThermodynamicTemperature::new::<degree_celsius>(25.0));
+//!
+//! // in the fan controller:
+//! let temp = BOARD_TEMP.get();
+//! assert_eq!(temp.get::<kelvin>(), 298.15);
+//! ```
+
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+use ::uom::si::{Dimension, Quantity, Units};
+use ::uom::Conversion;
+
+use crate::{Relaxable, RelaxedF32, RelaxedU32, RelaxedValue};
+
+/// A [`Quantity`] that always uses `Ordering::Relaxed` for access.
+///
+/// `D` and `U` are the dimension and unit system of the quantity, `V` is its
+/// storage type, either `f32` or `u32`.
+pub struct RelaxedQuantity<D: ?Sized, U: ?Sized, V: Relaxable> {
+    value: V::Relaxed,
+    quantity: PhantomData<fn() -> (*const D, *const U)>,
+}
+
+macro_rules! quantity_new {
+    ($storage:ident: $relaxed:ident) => {
+        impl<D, U> RelaxedQuantity<D, U, $storage>
+        where
+            D: Dimension + ?Sized,
+            U: Units<$storage> + ?Sized,
+        {
+            /// Create a new value.
+            #[inline(always)]
+            pub const fn new(val: Quantity<D, U, $storage>) -> Self {
+                Self::from_base(val.value)
+            }
+
+            /// Create a new value from its magnitude in the base units of `U`.
+            ///
+            /// Unlike most `uom` constructors this is `const`, so it can initialize a `static`.
+            #[inline(always)]
+            pub const fn from_base(val: $storage) -> Self {
+                RelaxedQuantity {
+                    value: $relaxed::new(val),
+                    quantity: PhantomData,
+                }
+            }
+        }
+
+        impl<D, U> Default for RelaxedQuantity<D, U, $storage>
+        where
+            D: Dimension + ?Sized,
+            U: Units<$storage> + ?Sized,
+        {
+            fn default() -> Self {
+                Self::from_base(0 as $storage)
+            }
+        }
+
+        impl<D, U> From<Quantity<D, U, $storage>> for RelaxedQuantity<D, U, $storage>
+        where
+            D: Dimension + ?Sized,
+            U: Units<$storage> + ?Sized,
+        {
+            fn from(val: Quantity<D, U, $storage>) -> Self {
+                Self::new(val)
+            }
+        }
+    };
+}
+
+quantity_new!(f32: RelaxedF32);
+quantity_new!(u32: RelaxedU32);
+
+impl<D, U, V> RelaxedQuantity<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: ::uom::num_traits::Num + Conversion<V> + Relaxable,
+{
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> Quantity<D, U, V> {
+        quantity(self.value.get())
+    }
+    /// Performs an atomic store with relaxed ordering.
+    #[inline(always)]
+    pub fn set(&self, val: Quantity<D, U, V>) {
+        self.value.set(val.value)
+    }
+    /// Stores a value, returning the previous value.
+    #[inline(always)]
+    pub fn swap(&self, val: Quantity<D, U, V>) -> Quantity<D, U, V> {
+        quantity(self.value.swap(val.value))
+    }
+    /// Stores `new` if the current value is `current`.
+    /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+    #[inline(always)]
+    pub fn compare_exchange(
+        &self,
+        current: Quantity<D, U, V>,
+        new: Quantity<D, U, V>,
+    ) -> Result<Quantity<D, U, V>, Quantity<D, U, V>> {
+        self.value
+            .compare_exchange(current.value, new.value)
+            .map(quantity)
+            .map_err(quantity)
+    }
+
+    /// Returns the stored magnitude in the base units of `U`.
+    #[inline(always)]
+    pub fn get_base(&self) -> V {
+        self.value.get()
+    }
+}
+
+impl<D, U, V> RelaxedValue for RelaxedQuantity<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: ::uom::num_traits::Num + Conversion<V> + Relaxable,
+{
+    type Value = Quantity<D, U, V>;
+
+    #[inline(always)]
+    fn get(&self) -> Quantity<D, U, V> {
+        RelaxedQuantity::get(self)
+    }
+    #[inline(always)]
+    fn set(&self, val: Quantity<D, U, V>) {
+        RelaxedQuantity::set(self, val)
+    }
+    #[inline(always)]
+    fn swap(&self, val: Quantity<D, U, V>) -> Quantity<D, U, V> {
+        RelaxedQuantity::swap(self, val)
+    }
+    #[inline(always)]
+    fn compare_exchange(
+        &self,
+        current: Quantity<D, U, V>,
+        new: Quantity<D, U, V>,
+    ) -> Result<Quantity<D, U, V>, Quantity<D, U, V>> {
+        RelaxedQuantity::compare_exchange(self, current, new)
+    }
+}
+
+impl<D, U, V> Debug for RelaxedQuantity<D, U, V>
+where
+    D: ?Sized,
+    U: ?Sized,
+    V: Relaxable + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.value.get(), f)
+    }
+}
+
+#[inline(always)]
+fn quantity<D, U, V>(value: V) -> Quantity<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: ::uom::num_traits::Num + Conversion<V>,
+{
+    Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value,
+    }
+}
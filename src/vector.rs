@@ -0,0 +1,206 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::seqlock::SeqLock;
+use crate::{RelaxedF32, RelaxedI16, RelaxedValue};
+
+macro_rules! vector_impls {
+    ($name:ident: $relaxed:ident, $inner:ty, $bits:path, $mint:ident, $len:literal, [$($field:ident: $index:literal),+]) => {
+        impl $name {
+            /// Create a new value.
+            #[inline(always)]
+            pub const fn new(val: [$inner; $len]) -> Self {
+                $name {
+                    $($field: $relaxed::new(val[$index]),)+
+                    lock: SeqLock::new(),
+                }
+            }
+
+            /// Returns all components, read without a concurrent write.
+            pub fn get(&self) -> [$inner; $len] {
+                self.lock.read(|| [$(self.$field.get()),+])
+            }
+            /// Replaces all components together.
+            pub fn set(&self, val: [$inner; $len]) {
+                self.lock.write(|| {
+                    $(self.$field.set(val[$index]);)+
+                })
+            }
+            /// Replaces all components together, returning the previous components.
+            pub fn swap(&self, val: [$inner; $len]) -> [$inner; $len] {
+                self.lock.write(|| [$(self.$field.swap(val[$index])),+])
+            }
+            /// Stores `new` if the current components are `current`, compared like the components' own
+            /// `compare_exchange`.
+            /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+            pub fn compare_exchange(
+                &self,
+                current: [$inner; $len],
+                new: [$inner; $len],
+            ) -> Result<[$inner; $len], [$inner; $len]> {
+                self.lock.write(|| {
+                    let previous = [$(self.$field.get()),+];
+                    if previous.map($bits) != current.map($bits) {
+                        return Err(previous);
+                    }
+                    $(self.$field.set(new[$index]);)+
+                    Ok(previous)
+                })
+            }
+        }
+
+        impl RelaxedValue for $name {
+            type Value = [$inner; $len];
+
+            #[inline(always)]
+            fn get(&self) -> [$inner; $len] {
+                $name::get(self)
+            }
+            #[inline(always)]
+            fn set(&self, val: [$inner; $len]) {
+                $name::set(self, val)
+            }
+            #[inline(always)]
+            fn swap(&self, val: [$inner; $len]) -> [$inner; $len] {
+                $name::swap(self, val)
+            }
+            #[inline(always)]
+            fn compare_exchange(
+                &self,
+                current: [$inner; $len],
+                new: [$inner; $len],
+            ) -> Result<[$inner; $len], [$inner; $len]> {
+                $name::compare_exchange(self, current, new)
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new([0 as $inner; $len])
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl From<[$inner; $len]> for $name {
+            fn from(val: [$inner; $len]) -> Self {
+                Self::new(val)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            /// Serializes the components as a tuple, read without a concurrent write.
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serde::Serialize::serialize(&self.get(), serializer)
+            }
+        }
+
+        #[cfg(feature = "mint")]
+        impl From<mint::$mint<$inner>> for $name {
+            fn from(val: mint::$mint<$inner>) -> Self {
+                Self::new(val.into())
+            }
+        }
+
+        #[cfg(feature = "mint")]
+        impl From<&$name> for mint::$mint<$inner> {
+            fn from(val: &$name) -> Self {
+                val.get().into()
+            }
+        }
+    };
+}
+
+/// Two `f32` components that are always read as a consistent pair.
+///
+/// Writes are guarded by a sequence lock, so a reading written as a unit, e.g. by an ISR,
+/// is never observed half updated.
+/// Readers retry while a write is in progress,
+/// so don't read from an interrupt that can preempt the writer.
+///
+/// ```
+/// use relaxed::RelaxedVec2F32;
+///
+/// static JOYSTICK: RelaxedVec2F32 = RelaxedVec2F32::new([0.0, 0.0]);
+///
+/// // in the ADC interrupt:
+/// JOYSTICK.set([0.25, -0.5]);
+///
+/// // in the main loop:
+/// let [x, y] = JOYSTICK.get();
+/// assert_eq!((x, y), (0.25, -0.5));
+/// ```
+pub struct RelaxedVec2F32 {
+    x: RelaxedF32,
+    y: RelaxedF32,
+    lock: SeqLock,
+}
+
+/// Three `f32` components that are always read as a consistent triple.
+///
+/// See [`RelaxedVec2F32`] for the guarantees.
+///
+/// ```
+/// use relaxed::RelaxedVec3F32;
+///
+/// static ACCEL: RelaxedVec3F32 = RelaxedVec3F32::new([0.0, 0.0, 9.81]);
+///
+/// assert_eq!(ACCEL.swap([0.1, 0.2, 9.7]), [0.0, 0.0, 9.81]);
+/// assert_eq!(ACCEL.get(), [0.1, 0.2, 9.7]);
+/// ```
+pub struct RelaxedVec3F32 {
+    x: RelaxedF32,
+    y: RelaxedF32,
+    z: RelaxedF32,
+    lock: SeqLock,
+}
+
+/// Two `i16` components that are always read as a consistent pair.
+///
+/// See [`RelaxedVec2F32`] for the guarantees.
+///
+/// ```
+/// use relaxed::RelaxedVec2I16;
+///
+/// static TOUCH: RelaxedVec2I16 = RelaxedVec2I16::new([0, 0]);
+///
+/// TOUCH.set([120, -40]);
+/// assert_eq!(TOUCH.compare_exchange([120, -40], [0, 0]), Ok([120, -40]));
+/// ```
+pub struct RelaxedVec2I16 {
+    x: RelaxedI16,
+    y: RelaxedI16,
+    lock: SeqLock,
+}
+
+/// Three `i16` components that are always read as a consistent triple,
+/// e.g. the raw axes of an IMU.
+///
+/// See [`RelaxedVec2F32`] for the guarantees.
+///
+/// ```
+/// use relaxed::RelaxedVec3I16;
+///
+/// static GYRO_RAW: RelaxedVec3I16 = RelaxedVec3I16::new([0; 3]);
+///
+/// GYRO_RAW.set([-12, 4, 1024]);
+/// assert_eq!(GYRO_RAW.get(), [-12, 4, 1024]);
+/// ```
+pub struct RelaxedVec3I16 {
+    x: RelaxedI16,
+    y: RelaxedI16,
+    z: RelaxedI16,
+    lock: SeqLock,
+}
+
+vector_impls!(RelaxedVec2F32: RelaxedF32, f32, f32::to_bits, Vector2, 2, [x: 0, y: 1]);
+vector_impls!(RelaxedVec3F32: RelaxedF32, f32, f32::to_bits, Vector3, 3, [x: 0, y: 1, z: 2]);
+vector_impls!(RelaxedVec2I16: RelaxedI16, i16, core::convert::identity, Vector2, 2, [x: 0, y: 1]);
+vector_impls!(RelaxedVec3I16: RelaxedI16, i16, core::convert::identity, Vector3, 3, [x: 0, y: 1, z: 2]);
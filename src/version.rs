@@ -0,0 +1,52 @@
+use core::fmt::{Display, Formatter};
+
+use crate::RelaxedVersion;
+
+/// Packs the version as `0xMMmmpppp`, so packed versions compare like the tuples.
+pub(crate) const fn pack((major, minor, patch): (u8, u8, u16)) -> u32 {
+    (major as u32) << 24 | (minor as u32) << 16 | patch as u32
+}
+
+pub(crate) const fn unpack(bits: u32) -> (u8, u8, u16) {
+    ((bits >> 24) as u8, (bits >> 16) as u8, bits as u16)
+}
+
+impl RelaxedVersion {
+    /// Returns the major version.
+    #[inline(always)]
+    pub fn major(&self) -> u8 {
+        self.get().0
+    }
+    /// Returns the minor version.
+    #[inline(always)]
+    pub fn minor(&self) -> u8 {
+        self.get().1
+    }
+    /// Returns the patch version.
+    #[inline(always)]
+    pub fn patch(&self) -> u16 {
+        self.get().2
+    }
+    /// Returns whether the version is `major.minor.patch` or newer.
+    ///
+    /// ```
+    /// use relaxed::RelaxedVersion;
+    ///
+    /// static PROTOCOL: RelaxedVersion = RelaxedVersion::new((2, 1, 0));
+    ///
+    /// assert!(PROTOCOL.is_at_least(2, 0, 7));
+    /// assert!(!PROTOCOL.is_at_least(2, 1, 1));
+    /// assert_eq!(PROTOCOL.to_string(), "2.1.0");
+    /// ```
+    #[inline(always)]
+    pub fn is_at_least(&self, major: u8, minor: u8, patch: u16) -> bool {
+        self.get() >= (major, minor, patch)
+    }
+}
+
+impl Display for RelaxedVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (major, minor, patch) = self.get();
+        write!(f, "{major}.{minor}.{patch}")
+    }
+}
@@ -0,0 +1,123 @@
+use core::cell::UnsafeCell;
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::{AtomicU8, Ordering};
+use core::task::Waker;
+
+const WAITING: u8 = 0;
+const REGISTERING: u8 = 0b01;
+const WAKING: u8 = 0b10;
+
+/// A slot for a single [`Waker`], safe to register and wake from different contexts.
+///
+/// This uses the same algorithm as `futures::task::AtomicWaker`,
+/// so it can be used to build futures on top of relaxed flags:
+/// the future registers its waker and then checks the flag,
+/// the other side sets the flag and then calls [`wake`](Self::wake).
+/// Handing over the waker needs acquire/release ordering, which this type uses internally.
+///
+/// ```
+/// use core::task::Waker;
+/// use relaxed::WakerSlot;
+///
+/// static SLOT: WakerSlot = WakerSlot::new();
+///
+/// SLOT.register(Waker::noop());
+/// SLOT.wake();
+/// assert!(SLOT.take().is_none());
+/// ```
+pub struct WakerSlot {
+    state: AtomicU8,
+    waker: UnsafeCell<Option<Waker>>,
+}
+
+// SAFETY: access to `waker` is serialized by the `state` machine
+unsafe impl Send for WakerSlot {}
+// SAFETY: access to `waker` is serialized by the `state` machine
+unsafe impl Sync for WakerSlot {}
+
+impl WakerSlot {
+    /// Create a new empty slot.
+    pub const fn new() -> Self {
+        WakerSlot {
+            state: AtomicU8::new(WAITING),
+            waker: UnsafeCell::new(None),
+        }
+    }
+
+    /// Stores `waker`, replacing the previously registered one.
+    ///
+    /// If a concurrent [`wake`](Self::wake) is in progress, `waker` is woken instead.
+    pub fn register(&self, waker: &Waker) {
+        match self
+            .state
+            .compare_exchange(WAITING, REGISTERING, Ordering::Acquire, Ordering::Acquire)
+            .unwrap_or_else(|state| state)
+        {
+            WAITING => {
+                // SAFETY: the REGISTERING bit gives exclusive access to `waker`
+                let previous = unsafe {
+                    let slot = &mut *self.waker.get();
+                    match slot {
+                        Some(old) if old.will_wake(waker) => None,
+                        _ => slot.replace(waker.clone()),
+                    }
+                };
+                let result = self.state.compare_exchange(
+                    REGISTERING,
+                    WAITING,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                );
+                if result.is_err() {
+                    // a wake happened while registering, it is now our job to wake
+                    // SAFETY: the REGISTERING bit is still set
+                    let waker = unsafe { (*self.waker.get()).take() };
+                    self.state.swap(WAITING, Ordering::AcqRel);
+                    if let Some(waker) = waker {
+                        waker.wake();
+                    }
+                }
+                drop(previous);
+            }
+            WAKING => {
+                // a wake is in progress and might miss the new waker, so wake it right away
+                waker.wake_by_ref();
+            }
+            _ => {
+                // a concurrent register call, one of the two wakers is registered
+            }
+        }
+    }
+
+    /// Removes and returns the registered waker, if any.
+    pub fn take(&self) -> Option<Waker> {
+        match self.state.fetch_or(WAKING, Ordering::AcqRel) {
+            WAITING => {
+                // SAFETY: the WAKING bit gives exclusive access to `waker`
+                let waker = unsafe { (*self.waker.get()).take() };
+                self.state.fetch_and(!WAKING, Ordering::Release);
+                waker
+            }
+            _ => None,
+        }
+    }
+
+    /// Wakes the registered waker, if any.
+    pub fn wake(&self) {
+        if let Some(waker) = self.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Default for WakerSlot {
+    fn default() -> Self {
+        WakerSlot::new()
+    }
+}
+
+impl Debug for WakerSlot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("WakerSlot")
+    }
+}
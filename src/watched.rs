@@ -0,0 +1,131 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::{RelaxedFn1, RelaxedValue};
+
+/// A relaxed value that can be armed with a watchpoint, to find out which context stores
+/// an unexpected value.
+///
+/// Every store through the wrapper checks the new value against the armed predicate,
+/// and fails a `debug_assert!` if it returns `true`, so a debugger stops on the offending store.
+/// In release builds the check is compiled out.
+///
+/// ```should_panic
+/// use relaxed::{RelaxedU32, Watched};
+///
+/// static SPEED: Watched<RelaxedU32> = Watched::new(RelaxedU32::new(0));
+///
+/// SPEED.watch(|speed| speed > 1000);
+/// SPEED.set(900);
+/// SPEED.set(1200); // panics in debug builds
+/// ```
+pub struct Watched<R: RelaxedValue> {
+    value: R,
+    watch: RelaxedFn1<R::Value, bool>,
+}
+
+impl<R: RelaxedValue> Watched<R> {
+    /// Wraps `value` without an armed watchpoint.
+    #[inline(always)]
+    pub const fn new(value: R) -> Self {
+        Watched {
+            value,
+            watch: RelaxedFn1::noop(),
+        }
+    }
+
+    /// Arms the watchpoint, triggering on every store for which `pred` returns `true`.
+    #[inline(always)]
+    pub fn watch(&self, pred: fn(R::Value) -> bool) {
+        self.watch.set(pred)
+    }
+    /// Disarms the watchpoint.
+    #[inline(always)]
+    pub fn unwatch(&self) {
+        self.watch.set(|_| false)
+    }
+    /// Returns the wrapped value, to access it without checks.
+    #[inline(always)]
+    pub fn inner(&self) -> &R {
+        &self.value
+    }
+
+    #[inline(always)]
+    fn check(&self, val: R::Value)
+    where
+        R::Value: Debug,
+    {
+        debug_assert!(!self.watch.call(val), "watchpoint triggered by {val:?}");
+    }
+}
+
+impl<R: RelaxedValue> Watched<R>
+where
+    R::Value: Debug,
+{
+    /// Performs an atomic load with relaxed ordering.
+    #[inline(always)]
+    pub fn get(&self) -> R::Value {
+        self.value.get()
+    }
+    /// Performs an atomic store with relaxed ordering, checking the watchpoint first.
+    #[inline(always)]
+    pub fn set(&self, val: R::Value) {
+        self.check(val);
+        self.value.set(val)
+    }
+    /// Stores a value, returning the previous value, checking the watchpoint first.
+    #[inline(always)]
+    pub fn swap(&self, val: R::Value) -> R::Value {
+        self.check(val);
+        self.value.swap(val)
+    }
+    /// Stores `new` if the current value is `current`, checking the watchpoint first.
+    /// Returns `Ok(previous_value)` on success, else `Err(current_value)`.
+    #[inline(always)]
+    pub fn compare_exchange(&self, current: R::Value, new: R::Value) -> Result<R::Value, R::Value> {
+        self.check(new);
+        self.value.compare_exchange(current, new)
+    }
+}
+
+impl<R: RelaxedValue> RelaxedValue for Watched<R>
+where
+    R::Value: Debug,
+{
+    type Value = R::Value;
+
+    #[inline(always)]
+    fn get(&self) -> R::Value {
+        Watched::get(self)
+    }
+    #[inline(always)]
+    fn set(&self, val: R::Value) {
+        Watched::set(self, val)
+    }
+    #[inline(always)]
+    fn swap(&self, val: R::Value) -> R::Value {
+        Watched::swap(self, val)
+    }
+    #[inline(always)]
+    fn compare_exchange(&self, current: R::Value, new: R::Value) -> Result<R::Value, R::Value> {
+        Watched::compare_exchange(self, current, new)
+    }
+}
+
+impl<R: RelaxedValue + Default> Default for Watched<R> {
+    fn default() -> Self {
+        Watched::new(R::default())
+    }
+}
+
+impl<R: RelaxedValue + Debug> Debug for Watched<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<R: RelaxedValue> From<R> for Watched<R> {
+    fn from(value: R) -> Self {
+        Watched::new(value)
+    }
+}
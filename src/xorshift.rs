@@ -0,0 +1,72 @@
+use core::fmt::{Debug, Formatter};
+
+use crate::RelaxedU32;
+
+/// Replaces a zero seed, which xorshift can't leave.
+const DEFAULT_SEED: u32 = 0x9e37_79b9;
+
+const fn step(mut x: u32) -> u32 {
+    x ^= x << 13;
+    x ^= x >> 17;
+    x ^= x << 5;
+    x
+}
+
+/// A xorshift32 pseudo random number generator whose state is a single atomic,
+/// so interrupts and tasks can share it without a lock.
+///
+/// Every call to [`next`](Self::next) advances the state with a compare and exchange,
+/// so concurrent callers never draw the same number.
+/// It's meant for jitter and backoff, not for cryptography.
+///
+/// ```
+/// use relaxed::RelaxedXorShift32;
+///
+/// static RNG: RelaxedXorShift32 = RelaxedXorShift32::new(1);
+///
+/// let a = RNG.next();
+/// let b = RNG.next();
+/// assert_ne!(a, b);
+/// assert!(RNG.next_below(10) < 10);
+/// ```
+#[repr(transparent)]
+pub struct RelaxedXorShift32(RelaxedU32);
+
+impl RelaxedXorShift32 {
+    /// Create a new generator. A `seed` of zero is replaced by a fixed non-zero seed.
+    #[inline(always)]
+    pub const fn new(seed: u32) -> Self {
+        RelaxedXorShift32(RelaxedU32::new(if seed == 0 { DEFAULT_SEED } else { seed }))
+    }
+
+    /// Returns the next pseudo random number.
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&self) -> u32 {
+        self.0.update_and_get(step)
+    }
+    /// Returns a pseudo random number below `n`, or zero if `n` is zero.
+    #[inline]
+    pub fn next_below(&self, n: u32) -> u32 {
+        ((self.next() as u64 * n as u64) >> 32) as u32
+    }
+    /// Replaces the state. A `seed` of zero is replaced by a fixed non-zero seed.
+    #[inline(always)]
+    pub fn reseed(&self, seed: u32) {
+        self.0.set(if seed == 0 { DEFAULT_SEED } else { seed })
+    }
+}
+
+impl Default for RelaxedXorShift32 {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl Debug for RelaxedXorShift32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RelaxedXorShift32")
+            .field(&self.0.get())
+            .finish()
+    }
+}
@@ -0,0 +1,92 @@
+//! Properties of the read-modify-write helpers.
+
+use std::thread;
+
+use proptest::prelude::*;
+use relaxed::{RelaxedF32, RelaxedI32, RelaxedOption, RelaxedU32, RelaxedU8, UpdateError};
+
+proptest! {
+    #[test]
+    fn get_and_update_returns_previous(initial: u32, add: u32) {
+        let value = RelaxedU32::new(initial);
+        prop_assert_eq!(value.get_and_update(|v| v.wrapping_add(add)), initial);
+        prop_assert_eq!(value.get(), initial.wrapping_add(add));
+    }
+
+    #[test]
+    fn update_and_get_returns_new(initial: i32, mul: i32) {
+        let value = RelaxedI32::new(initial);
+        let new = initial.wrapping_mul(mul);
+        prop_assert_eq!(value.update_and_get(|v| v.wrapping_mul(mul)), new);
+        prop_assert_eq!(value.get(), new);
+    }
+
+    #[test]
+    fn fetch_update_none_leaves_value(initial: u8) {
+        let value = RelaxedU8::new(initial);
+        prop_assert_eq!(value.fetch_update(|_| None), Err(initial));
+        prop_assert_eq!(value.get(), initial);
+    }
+
+    #[test]
+    fn fetch_update_bounded_uncontended_succeeds(initial: u32, retries in 0u32..4) {
+        let value = RelaxedU32::new(initial);
+        prop_assert_eq!(value.fetch_update_bounded(retries, |v| v.checked_add(1)), match initial {
+            u32::MAX => Err(UpdateError::Rejected(initial)),
+            _ => Ok(initial),
+        });
+    }
+
+    #[test]
+    fn try_update_uncontended_succeeds(initial: u32, new: u32) {
+        let value = RelaxedU32::new(initial);
+        prop_assert_eq!(value.try_update(|_| new), Ok(initial));
+        prop_assert_eq!(value.get(), new);
+    }
+
+    #[test]
+    fn with_value_restores(initial: u32, temp: u32) {
+        let value = RelaxedU32::new(initial);
+        let seen = value.with_value(temp, || value.get());
+        prop_assert_eq!(seen, temp);
+        prop_assert_eq!(value.get(), initial);
+    }
+
+    #[test]
+    fn float_rmw_matches_plain_arithmetic(initial: f32, operand: f32) {
+        let value = RelaxedF32::new(initial);
+        prop_assert_eq!(value.fetch_mul(operand).to_bits(), initial.to_bits());
+        prop_assert_eq!(value.get().to_bits(), (initial * operand).to_bits());
+        value.set(initial);
+        value.fetch_div(operand);
+        prop_assert_eq!(value.get().to_bits(), (initial / operand).to_bits());
+    }
+
+    #[test]
+    fn option_round_trips(val: Option<u32>) {
+        let option = RelaxedOption::none();
+        match val {
+            Some(u32::MAX) => prop_assert_eq!(option.set(val), Err(u32::MAX)),
+            _ => prop_assert_eq!(option.set(val), Ok(())),
+        }
+        let expected = val.filter(|&v| v != u32::MAX);
+        prop_assert_eq!(option.get(), expected);
+        prop_assert_eq!(option.take(), expected);
+        prop_assert_eq!(option.get(), None);
+    }
+
+    #[test]
+    fn concurrent_get_and_update_loses_no_increments(threads in 1usize..8, per_thread in 1u32..1000) {
+        let value = RelaxedU32::new(0);
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| {
+                    for _ in 0..per_thread {
+                        value.get_and_update(|v| v + 1);
+                    }
+                });
+            }
+        });
+        prop_assert_eq!(value.get(), threads as u32 * per_thread);
+    }
+}
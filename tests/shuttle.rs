@@ -0,0 +1,138 @@
+//! Randomized scheduling tests, run with
+//! `RUSTFLAGS="--cfg relaxed_shuttle" cargo test --test shuttle`.
+#![cfg(relaxed_shuttle)]
+
+use std::sync::Arc;
+
+use relaxed::{RelaxedBool, RelaxedOption, RelaxedU32, UpdateError};
+use shuttle::sync::atomic::{AtomicU32, Ordering};
+use shuttle::thread;
+
+const ITERATIONS: usize = 1000;
+const THREADS: u32 = 3;
+
+#[test]
+fn option_set_if_none_claims_once() {
+    shuttle::check_random(
+        || {
+            let slot = Arc::new(RelaxedOption::<u32>::none());
+            let handles: Vec<_> = (0..THREADS)
+                .map(|i| {
+                    let slot = slot.clone();
+                    thread::spawn(move || slot.set_if_none(i))
+                })
+                .collect();
+            let winners = handles
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .filter(|&won| won)
+                .count();
+            assert_eq!(winners, 1);
+            assert!(slot.get().is_some());
+        },
+        ITERATIONS,
+    );
+}
+
+#[test]
+fn option_take_hands_off_once() {
+    shuttle::check_random(
+        || {
+            let slot = Arc::new(RelaxedOption::<u32>::none());
+            let producer = {
+                let slot = slot.clone();
+                thread::spawn(move || slot.set(Some(7)).unwrap())
+            };
+            let consumers: Vec<_> = (0..2)
+                .map(|_| {
+                    let slot = slot.clone();
+                    thread::spawn(move || slot.take())
+                })
+                .collect();
+            producer.join().unwrap();
+            let taken: Vec<_> = consumers
+                .into_iter()
+                .filter_map(|h| h.join().unwrap())
+                .chain(slot.take())
+                .collect();
+            assert_eq!(taken, [7]);
+        },
+        ITERATIONS,
+    );
+}
+
+#[test]
+fn try_guard_is_exclusive() {
+    shuttle::check_random(
+        || {
+            let busy = Arc::new(RelaxedBool::new(false));
+            let inside = Arc::new(AtomicU32::new(0));
+            let handles: Vec<_> = (0..THREADS)
+                .map(|_| {
+                    let busy = busy.clone();
+                    let inside = inside.clone();
+                    thread::spawn(move || {
+                        if let Some(_guard) = busy.try_guard() {
+                            assert_eq!(inside.fetch_add(1, Ordering::Relaxed), 0);
+                            thread::yield_now();
+                            inside.fetch_sub(1, Ordering::Relaxed);
+                        }
+                    })
+                })
+                .collect();
+            for h in handles {
+                h.join().unwrap();
+            }
+            assert!(!busy.get());
+        },
+        ITERATIONS,
+    );
+}
+
+#[test]
+fn update_loops_lose_no_increments() {
+    shuttle::check_random(
+        || {
+            let counter = Arc::new(RelaxedU32::new(0));
+            let handles: Vec<_> = (0..THREADS)
+                .map(|_| {
+                    let counter = counter.clone();
+                    thread::spawn(move || {
+                        counter.get_and_update(|x| x + 1);
+                        counter.fetch_update(|x| Some(x + 1)).unwrap();
+                    })
+                })
+                .collect();
+            for h in handles {
+                h.join().unwrap();
+            }
+            assert_eq!(counter.get(), 2 * THREADS);
+        },
+        ITERATIONS,
+    );
+}
+
+#[test]
+fn bounded_update_counts_only_successes() {
+    shuttle::check_random(
+        || {
+            let counter = Arc::new(RelaxedU32::new(0));
+            let handles: Vec<_> = (0..THREADS)
+                .map(|_| {
+                    let counter = counter.clone();
+                    thread::spawn(
+                        move || match counter.fetch_update_bounded(0, |x| Some(x + 1)) {
+                            Ok(_) => 1,
+                            Err(UpdateError::Exhausted(_)) => 0,
+                            Err(UpdateError::Rejected(_)) => unreachable!(),
+                        },
+                    )
+                })
+                .collect();
+            let successes: u32 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+            assert!(successes >= 1);
+            assert_eq!(counter.get(), successes);
+        },
+        ITERATIONS,
+    );
+}